@@ -0,0 +1,123 @@
+//! `#[derive(ToMeta)]` for [substreams-sink-winston](https://docs.rs/substreams-sink-winston)
+//!
+//! Enabled with the `derive` feature of `substreams-sink-winston`, which re-exports the macro.
+//!
+//! ### Field attributes
+//! - `#[meta(rename = "key")]` use `key` instead of the field name
+//! - `#[meta(skip)]` leave the field out of the meta
+//! - `#[meta(private)]` insert the field as private, stripped by `ignore_private`
+//! - `#[meta(hex)]` render bytes (`AsRef<[u8]>`) as a `0x` prefixed hex string
+//! - `#[meta(decimals = 18)]` render a raw integer amount as a decimal string
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Field, Fields, LitInt, LitStr, Result};
+
+#[proc_macro_derive(ToMeta, attributes(meta))]
+pub fn derive_to_meta(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "ToMeta can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "ToMeta can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut inserts = Vec::new();
+    for field in fields {
+        let attrs = FieldAttrs::parse(field)?;
+        if attrs.skip {
+            continue;
+        }
+        let ident = field.ident.as_ref().expect("named field");
+        let key = attrs.rename.unwrap_or_else(|| ident.to_string());
+        let value = match (attrs.hex, attrs.decimals) {
+            (true, _) => quote!(::substreams_sink_winston::__private::hex(&self.#ident)),
+            (_, Some(decimals)) => {
+                quote!(::substreams_sink_winston::__private::decimals(&self.#ident, #decimals))
+            }
+            _ => quote!(::core::clone::Clone::clone(&self.#ident)),
+        };
+        let insert = if attrs.private {
+            quote!(insert_private)
+        } else {
+            quote!(insert)
+        };
+        inserts.push(quote!(meta.#insert(#key, #value);));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::substreams_sink_winston::ToMeta for #name #ty_generics #where_clause {
+            fn to_meta(&self) -> ::substreams_sink_winston::Meta {
+                let mut meta = ::substreams_sink_winston::Meta::new();
+                #(#inserts)*
+                meta
+            }
+        }
+    })
+}
+
+#[derive(Default)]
+struct FieldAttrs {
+    rename: Option<String>,
+    skip: bool,
+    private: bool,
+    hex: bool,
+    decimals: Option<u32>,
+}
+
+impl FieldAttrs {
+    fn parse(field: &Field) -> Result<Self> {
+        let mut attrs = FieldAttrs::default();
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("meta"))
+        {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    attrs.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+                } else if meta.path.is_ident("skip") {
+                    attrs.skip = true;
+                } else if meta.path.is_ident("private") {
+                    attrs.private = true;
+                } else if meta.path.is_ident("hex") {
+                    attrs.hex = true;
+                } else if meta.path.is_ident("decimals") {
+                    attrs.decimals = Some(meta.value()?.parse::<LitInt>()?.base10_parse()?);
+                } else {
+                    return Err(meta.error(
+                        "unknown meta attribute, expected rename, skip, private, hex or decimals",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+        if attrs.hex && attrs.decimals.is_some() {
+            return Err(Error::new_spanned(
+                field,
+                "meta attributes `hex` and `decimals` are mutually exclusive",
+            ));
+        }
+        Ok(attrs)
+    }
+}
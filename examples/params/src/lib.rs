@@ -0,0 +1,25 @@
+use substreams::errors::Error;
+use substreams::pb::substreams::Clock;
+use substreams_sink_winston::{Logger, LoggerOperations, Meta};
+
+#[substreams::handlers::map]
+fn map_logs(params: String, clock: Clock) -> Result<LoggerOperations, Error> {
+    // Initialize Winston Logger operations container
+    let mut log_ops: LoggerOperations = Default::default();
+
+    // Create Logger from module params
+    // ex: level=warning&service=dex&meta.network=mainnet
+    let logger = Logger::new("params")
+        .with_params(&params)?
+        .with_clock(&clock);
+
+    let mut meta = Meta::new();
+    meta.insert("block", clock.number);
+
+    // Operations below the `level` param are not pushed
+    logger.push(&mut log_ops, logger.debug("debug message").with(meta.clone()));
+    logger.push(&mut log_ops, logger.info("info message").with(meta.clone()));
+    logger.push(&mut log_ops, logger.warning("warning message").with(meta));
+
+    Ok(log_ops)
+}
@@ -0,0 +1,115 @@
+use crate::{Level, Logger, LoggerOperation, LoggingLevels, Meta};
+use std::error::Error;
+
+impl Logger {
+    /// Error: error conditions, captured from a Rust error and its cause chain
+    ///
+    /// Similar to winston `format.errors({ stack: true })`, the message is the top-level error,
+    /// `causes` holds every `source()` message and `stack` the rendered chain.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// let err = "abc".parse::<u64>().unwrap_err();
+    /// logger.push(&mut log_ops, logger.error_from(&err));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn error_from(&self, err: &dyn Error) -> LoggerOperation {
+        self.log_error(LoggingLevels::Error, err)
+    }
+
+    /// Log a Rust error and its cause chain at a level picked at runtime
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggingLevels};
+    /// let logger = Logger::new("user-service");
+    /// let err = "abc".parse::<u64>().unwrap_err();
+    /// logger.log_error(LoggingLevels::Warning, &err);
+    /// ```
+    #[must_use]
+    pub fn log_error<L: Into<Level>>(&self, level: L, err: &dyn Error) -> LoggerOperation {
+        let message = err.to_string();
+        let mut causes: Vec<String> = vec![];
+        let mut source = err.source();
+        while let Some(cause) = source {
+            causes.push(cause.to_string());
+            source = cause.source();
+        }
+
+        let mut stack = message.clone();
+        if !causes.is_empty() {
+            stack.push_str("\n\nCaused by:");
+            for (index, cause) in causes.iter().enumerate() {
+                stack.push_str(&format!("\n    {}: {}", index, cause));
+            }
+        }
+
+        let mut meta = Meta::new();
+        meta.insert("causes", causes);
+        meta.insert("stack", stack);
+        self.log(level, &message).with(meta)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntoValue, Logger, LoggingLevels, Meta};
+    use std::{error::Error, fmt};
+
+    #[derive(Debug)]
+    struct ChainError(&'static str, Option<Box<ChainError>>);
+
+    impl fmt::Display for ChainError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl Error for ChainError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.1.as_deref().map(|err| err as &(dyn Error + 'static))
+        }
+    }
+
+    #[test]
+    fn test_error_from() {
+        let err = ChainError(
+            "failed to process block",
+            Some(Box::new(ChainError(
+                "failed to decode event",
+                Some(Box::new(ChainError("unexpected end of buffer", None))),
+            ))),
+        );
+        let operation = Logger::new("user-service").error_from(&err);
+        let meta = Meta::from(&operation);
+
+        assert_eq!(operation.message, "failed to process block");
+        assert_eq!(operation.level, LoggingLevels::Error as i32);
+        assert_eq!(
+            meta.get("causes"),
+            Some(&vec!["failed to decode event", "unexpected end of buffer"].into_value())
+        );
+        assert_eq!(
+            meta.get("stack"),
+            Some(
+                &"failed to process block\n\nCaused by:\n    0: failed to decode event\n    1: unexpected end of buffer"
+                    .into_value()
+            )
+        );
+    }
+
+    #[test]
+    fn test_log_error_without_source() {
+        let err = ChainError("failed", None);
+        let operation = Logger::new("user-service").log_error(LoggingLevels::Crit, &err);
+        let meta = Meta::from(&operation);
+
+        assert_eq!(operation.level, LoggingLevels::Crit as i32);
+        assert_eq!(meta.get("causes"), Some(&Vec::<String>::new().into_value()));
+        assert_eq!(meta.get("stack"), Some(&"failed".into_value()));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{LoggerOperation, LoggerOperations};
+
+impl LoggerOperation {
+    /// Mark operation as private, stripped by `LoggerOperations::ignore_private`
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::Logger;
+    /// let logger = Logger::new("user-service");
+    /// logger.info("message").private();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn private(self) -> Self {
+        LoggerOperation {
+            private: true,
+            ..self
+        }
+    }
+
+    /// Remove private `meta` entries
+    pub fn strip_private(&mut self) {
+        if let Some(meta) = &mut self.meta {
+            for k in &self.private_keys {
+                meta.fields.remove(k);
+            }
+        }
+        for k in &self.private_keys {
+            self.legacy_meta.remove(k);
+        }
+        self.private_keys.clear();
+    }
+}
+
+impl LoggerOperations {
+    /// Remove private operations and private `meta` entries, mirroring winston `format.ignorePrivate()`
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.info("public message"));
+    /// logger.push(&mut log_ops, logger.info("private message").private());
+    /// log_ops.ignore_private();
+    /// assert_eq!(log_ops.operations.len(), 1);
+    /// ```
+    pub fn ignore_private(&mut self) {
+        self.operations.retain(|operation| !operation.private);
+        for operation in &mut self.operations {
+            operation.strip_private();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Logger, LoggerOperations, Meta};
+
+    #[test]
+    fn test_ignore_private() {
+        let mut default_meta = Meta::new();
+        default_meta.insert_private("api_key", "secret");
+        let logger = Logger::new("user-service").with_default_meta(default_meta);
+
+        let mut meta = Meta::new();
+        meta.insert("pool", "abc");
+        meta.insert_private("memo", "hello");
+
+        let mut log_ops: LoggerOperations = Default::default();
+        log_ops.push(logger.info("public message").with(meta));
+        log_ops.push(logger.info("private message").private());
+        log_ops.ignore_private();
+
+        assert_eq!(log_ops.operations.len(), 1);
+        let operation = &log_ops.operations[0];
+        let meta = Meta::from(operation);
+        assert_eq!(meta.len(), 1);
+        assert!(meta.get("pool").is_some());
+        assert!(operation.private_keys.is_empty());
+    }
+
+    #[test]
+    fn test_insert_public_overrides_private() {
+        let mut meta = Meta::new();
+        meta.insert_private("key", "secret");
+        meta.extend(Meta::from(vec![["key", "public"]]));
+
+        assert!(!meta.is_private("key"));
+    }
+}
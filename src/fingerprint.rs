@@ -0,0 +1,185 @@
+use crate::{helpers::encode_hex, value_to_json, LoggerOperation, LoggerOperations, Meta};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+
+impl LoggerOperation {
+    /// Stable fingerprint over service, level, message and selected meta keys
+    ///
+    /// Returns 16 bytes of SHA-256, lowercase hex encoded.
+    /// Meta keys are hashed in sorted order, missing keys are distinct from any value.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, Meta};
+    /// let logger = Logger::new("user-service");
+    /// let a = logger.info("message").with(Meta::from(vec!(["pool", "abc"], ["block", "1"])));
+    /// let b = logger.info("message").with(Meta::from(vec!(["pool", "abc"], ["block", "2"])));
+    /// assert_eq!(a.compute_fingerprint(&["pool"]), b.compute_fingerprint(&["pool"]));
+    /// ```
+    #[must_use]
+    pub fn compute_fingerprint(&self, meta_keys: &[&str]) -> String {
+        let mut keys = meta_keys.to_vec();
+        keys.sort_unstable();
+        keys.dedup();
+        self.hash_fingerprint(&keys)
+    }
+
+    /// Set fingerprint field over service, level, message and selected meta keys
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::Logger;
+    /// let logger = Logger::new("user-service");
+    /// let operation = logger.info("message").with_fingerprint(&[]);
+    /// assert_eq!(operation.fingerprint.unwrap().len(), 32);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_fingerprint(self, meta_keys: &[&str]) -> Self {
+        LoggerOperation {
+            fingerprint: Some(self.compute_fingerprint(meta_keys)),
+            ..self
+        }
+    }
+
+    /// Fingerprint field if set, otherwise fingerprint over every meta key
+    fn dedup_key(&self) -> String {
+        match &self.fingerprint {
+            Some(fingerprint) => fingerprint.to_owned(),
+            None => {
+                let meta = Meta::from(self);
+                let mut keys: Vec<&str> = meta.keys().collect();
+                keys.sort_unstable();
+                self.hash_fingerprint(&keys)
+            }
+        }
+    }
+
+    fn hash_fingerprint(&self, sorted_keys: &[&str]) -> String {
+        let meta = Meta::from(self);
+        let mut hasher = Sha256::new();
+        update(&mut hasher, self.service.as_bytes());
+        hasher.update(self.level.to_be_bytes());
+        update(&mut hasher, self.message.as_bytes());
+        for key in sorted_keys {
+            update(&mut hasher, key.as_bytes());
+            match meta.get(key) {
+                Some(value) => {
+                    hasher.update([1]);
+                    update(&mut hasher, value_to_json(value).as_bytes());
+                }
+                None => hasher.update([0]),
+            }
+        }
+        encode_hex(&hasher.finalize()[..16])
+    }
+}
+
+/// Length-prefixed update, so that adjacent fields can not collide
+fn update(hasher: &mut Sha256, bytes: &[u8]) {
+    hasher.update((bytes.len() as u64).to_be_bytes());
+    hasher.update(bytes);
+}
+
+impl LoggerOperations {
+    /// Remove duplicated operations within the batch, keeping the first occurrence
+    ///
+    /// Operations are compared by their `fingerprint` field if set,
+    /// otherwise by a fingerprint over service, level, message and every meta key.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.info("message"));
+    /// logger.push(&mut log_ops, logger.info("message"));
+    /// log_ops.dedup();
+    /// assert_eq!(log_ops.operations.len(), 1);
+    /// ```
+    pub fn dedup(&mut self) {
+        let mut seen = HashSet::new();
+        self.operations
+            .retain(|operation| seen.insert(operation.dedup_key()));
+    }
+
+    /// Remove duplicated operations within the batch, comparing only selected meta keys
+    pub fn dedup_by_meta_keys(&mut self, meta_keys: &[&str]) {
+        let mut seen = HashSet::new();
+        self.operations
+            .retain(|operation| seen.insert(operation.compute_fingerprint(meta_keys)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Logger, LoggerOperations, Meta};
+
+    #[test]
+    fn test_fingerprint() {
+        let logger = Logger::new("user-service");
+        let operation = logger
+            .info("message")
+            .with(Meta::from(vec![("pool", "abc")]));
+
+        assert_eq!(
+            operation.compute_fingerprint(&["pool", "missing"]),
+            operation.compute_fingerprint(&["missing", "pool", "pool"])
+        );
+        assert_ne!(
+            operation.compute_fingerprint(&["pool"]),
+            operation.compute_fingerprint(&[])
+        );
+        assert_ne!(
+            operation.compute_fingerprint(&["missing"]),
+            logger
+                .info("message")
+                .with(Meta::from(vec![("missing", "")]))
+                .compute_fingerprint(&["missing"])
+        );
+        assert_ne!(
+            operation.compute_fingerprint(&[]),
+            Logger::new("user-servic")
+                .info("emessage")
+                .compute_fingerprint(&[])
+        );
+    }
+
+    #[test]
+    fn test_dedup() {
+        let logger = Logger::new("user-service");
+        let mut log_ops: LoggerOperations = Default::default();
+        log_ops.push(logger.info("message").with(Meta::from(vec![("block", 1)])));
+        log_ops.push(logger.info("message").with(Meta::from(vec![("block", 2)])));
+        log_ops.push(logger.info("message").with(Meta::from(vec![("block", 1)])));
+        log_ops.push(logger.info("other"));
+        log_ops.dedup();
+
+        assert_eq!(log_ops.operations.len(), 3);
+
+        log_ops.dedup_by_meta_keys(&[]);
+        assert_eq!(log_ops.operations.len(), 2);
+        assert_eq!(log_ops.operations[1].message, "other");
+    }
+
+    #[test]
+    fn test_dedup_fingerprint_field() {
+        let logger = Logger::new("user-service");
+        let mut log_ops: LoggerOperations = Default::default();
+        log_ops.push(
+            logger
+                .info("message")
+                .with(Meta::from(vec![("block", 1)]))
+                .with_fingerprint(&[]),
+        );
+        log_ops.push(
+            logger
+                .info("message")
+                .with(Meta::from(vec![("block", 2)]))
+                .with_fingerprint(&[]),
+        );
+        log_ops.dedup();
+
+        assert_eq!(log_ops.operations.len(), 1);
+    }
+}
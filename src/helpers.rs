@@ -1,18 +1,158 @@
 use crate::{LoggerOperation, LoggerOperations};
+use std::fmt::Write;
+
+/// Lowercase hex encoding with `0x` prefix
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    format!("0x{}", encode_hex(bytes))
+}
+
+/// Lowercase hex encoding without prefix
+pub(crate) fn encode_hex(bytes: &[u8]) -> String {
+    let mut hex = String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        let _ = write!(hex, "{:02x}", byte);
+    }
+    hex
+}
+
+/// Maximum number of fractional digits rendered by `format_decimals`
+pub(crate) const MAX_DECIMALS: usize = 1024;
+
+/// Render a raw amount as a decimal string, shifted by `decimals`
+///
+/// Amounts already holding a decimal point are shifted further, trailing fractional
+/// zeros and leading integer zeros are trimmed.
+/// `None` unless the amount matches `-?digits(.digits)?` and fits `MAX_DECIMALS`.
+pub(crate) fn format_decimals(amount: &str, decimals: u32) -> Option<String> {
+    let (sign, amount) = match amount.strip_prefix('-') {
+        Some(amount) => ("-", amount),
+        None => ("", amount),
+    };
+    let is_digits = |s: &str| !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit());
+    let (digits, decimals) = match amount.split_once('.') {
+        Some((integer, fraction)) if is_digits(integer) && is_digits(fraction) => (
+            format!("{}{}", integer, fraction),
+            decimals as usize + fraction.len(),
+        ),
+        None if is_digits(amount) => (amount.to_string(), decimals as usize),
+        _ => return None,
+    };
+    if decimals > MAX_DECIMALS {
+        return None;
+    }
+    let digits = format!("{:0>width$}", digits, width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let integer = match integer.trim_start_matches('0') {
+        "" => "0",
+        integer => integer,
+    };
+    let fraction = fraction.trim_end_matches('0');
+    let sign = match integer == "0" && fraction.is_empty() {
+        true => "",
+        false => sign,
+    };
+    Some(match fraction.is_empty() {
+        true => format!("{}{}", sign, integer),
+        false => format!("{}{}.{}", sign, integer, fraction),
+    })
+}
 
 impl LoggerOperations {
+    /// Assigns the next `ordinal` to the operation, preserving emission order.
+    ///
+    /// Every operation is pushed: the Logger level threshold and key policy are only
+    /// applied by `Logger::push`.
     pub fn push(&mut self, operation: LoggerOperation) {
-        self.operations.push(operation);
+        let ordinal = self.next_ordinal();
+        self.operations.push(LoggerOperation {
+            ordinal,
+            ..operation
+        });
     }
 
+    /// Assigns the next `ordinal` to each operation, preserving emission order.
     pub fn extend(&mut self, operations: Vec<LoggerOperation>) {
-        self.operations.extend(operations);
+        let next = self.next_ordinal();
+        self.operations.reserve(operations.len());
+        for (ordinal, operation) in (next..).zip(operations) {
+            self.operations.push(LoggerOperation {
+                ordinal,
+                ..operation
+            });
+        }
+    }
+
+    /// Sort operations by block number then ordinal, ties keep their current order
+    ///
+    /// Ordinals are then renumbered in the sorted order, keeping the highest ordinal
+    /// on the last operation.
+    pub fn sort(&mut self) {
+        self.operations.sort_by_key(|operation| {
+            let number = operation.block.as_ref().map(|block| block.number);
+            (number.unwrap_or_default(), operation.ordinal)
+        });
+        for (ordinal, operation) in (0..).zip(&mut self.operations) {
+            operation.ordinal = ordinal;
+        }
+    }
+
+    /// Merge operations from another batch, sorted by block number then ordinal, see `sort`
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let logger = Logger::new("user-service");
+    /// let mut a: LoggerOperations = Default::default();
+    /// logger.push(&mut a, logger.info("a0"));
+    /// logger.push(&mut a, logger.info("a1"));
+    /// let mut b: LoggerOperations = Default::default();
+    /// logger.push(&mut b, logger.info("b0"));
+    /// a.merge(b);
+    /// assert_eq!(a.operations[1].message, "b0");
+    /// ```
+    pub fn merge(&mut self, other: LoggerOperations) {
+        self.operations.extend(other.operations);
+        self.sort();
+    }
+
+    /// Above the ordinal of the last operation, which `push`, `sort` and `merge` keep the highest
+    fn next_ordinal(&self) -> u64 {
+        self.operations
+            .last()
+            .map_or(0, |operation| operation.ordinal + 1)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Logger, LoggerOperations};
+    use super::{format_decimals, to_hex, MAX_DECIMALS};
+    use crate::{Block, Logger, LoggerOperations};
+
+    #[test]
+    fn test_to_hex() {
+        assert_eq!(to_hex(&[0x00, 0xab, 0xff]), "0x00abff");
+        assert_eq!(to_hex(&[]), "0x");
+    }
+
+    #[test]
+    fn test_format_decimals() {
+        assert_eq!(format_decimals("1500000000000000000", 18).unwrap(), "1.5");
+        assert_eq!(format_decimals("12345", 4).unwrap(), "1.2345");
+        assert_eq!(format_decimals("5", 3).unwrap(), "0.005");
+        assert_eq!(format_decimals("-250", 2).unwrap(), "-2.5");
+        assert_eq!(format_decimals("100", 2).unwrap(), "1");
+        assert_eq!(format_decimals("42", 0).unwrap(), "42");
+        assert_eq!(format_decimals("1.25", 2).unwrap(), "0.0125");
+        assert_eq!(format_decimals("0012.50", 0).unwrap(), "12.5");
+        assert_eq!(format_decimals("-0", 3).unwrap(), "0");
+        for invalid in [
+            "", "-", "é", "NaN", "inf", "1.2.3", "1.", ".5", "+1", "1e18", " 1",
+        ] {
+            assert_eq!(format_decimals(invalid, 1), None, "{:?}", invalid);
+        }
+        assert_eq!(format_decimals("1", u32::MAX), None);
+        assert_eq!(format_decimals("1.5", MAX_DECIMALS as u32), None);
+    }
 
     #[test]
     fn test_push() {
@@ -32,4 +172,74 @@ mod tests {
 
         assert_eq!(log_ops.operations.len(), 2);
     }
+
+    #[test]
+    fn test_ordinal() {
+        let mut log_ops: LoggerOperations = Default::default();
+        let logger = Logger::new("user-service");
+        log_ops.push(logger.info("message0"));
+        log_ops.extend(vec![logger.info("message1"), logger.info("message2")]);
+        log_ops.operations.remove(1);
+        log_ops.push(logger.info("message3"));
+
+        let ordinals: Vec<u64> = log_ops.operations.iter().map(|op| op.ordinal).collect();
+        assert_eq!(ordinals, vec![0, 2, 3]);
+    }
+
+    #[test]
+    fn test_ordinal_after_reorder() {
+        let block = |number| Block {
+            number,
+            ..Default::default()
+        };
+        let first = Logger::new("dex").with_clock(block(2));
+        let second = Logger::new("dex").with_clock(block(1));
+
+        let mut log_ops: LoggerOperations = Default::default();
+        log_ops.push(first.info("a"));
+        log_ops.push(second.info("b"));
+        log_ops.sort();
+        log_ops.push(first.info("c"));
+        assert_eq!(log_ops.operations[2].ordinal, 2);
+
+        let mut other: LoggerOperations = Default::default();
+        for message in ["d", "e", "f", "g"] {
+            other.push(second.info(message));
+        }
+        log_ops.merge(other);
+        log_ops.push(first.info("h"));
+        let messages: Vec<&str> = log_ops
+            .operations
+            .iter()
+            .map(|op| op.message.as_str())
+            .collect();
+        let ordinals: Vec<u64> = log_ops.operations.iter().map(|op| op.ordinal).collect();
+        assert_eq!(messages, vec!["b", "d", "e", "f", "g", "a", "c", "h"]);
+        assert_eq!(ordinals, (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_merge() {
+        let block = |number| Block {
+            number,
+            ..Default::default()
+        };
+        let first = Logger::new("dex").with_clock(block(2));
+        let second = Logger::new("nft").with_clock(block(1));
+
+        let mut log_ops: LoggerOperations = Default::default();
+        log_ops.push(first.info("dex0"));
+        log_ops.push(first.info("dex1"));
+        let mut other: LoggerOperations = Default::default();
+        other.push(second.info("nft0"));
+        other.push(first.info("dex0-other"));
+        log_ops.merge(other);
+
+        let messages: Vec<&str> = log_ops
+            .operations
+            .iter()
+            .map(|op| op.message.as_str())
+            .collect();
+        assert_eq!(messages, vec!["nft0", "dex0", "dex1", "dex0-other"]);
+    }
 }
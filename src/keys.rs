@@ -0,0 +1,364 @@
+use std::fmt;
+
+/// Keys of the winston info object, colliding with meta flattened downstream
+pub const RESERVED_KEYS: [&str; 4] = ["level", "message", "service", "timestamp"];
+
+/// Prefix given to reserved keys by `KeyAction::Rename`
+pub const RENAME_PREFIX: &str = "meta_";
+
+/// Invalid meta key
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeyError {
+    /// Key collides with a winston info object field
+    Reserved(String),
+    /// Key is empty or holds characters other than ASCII alphanumerics, `_`, `-` and `.`
+    InvalidFormat(String),
+    /// Renamed or normalized key overwrote an existing entry
+    Collision(String),
+}
+
+impl fmt::Display for KeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeyError::Reserved(k) => write!(f, "reserved meta key: {}", k),
+            KeyError::InvalidFormat(k) => write!(f, "invalid meta key: {:?}", k),
+            KeyError::Collision(k) => write!(f, "meta key collision: {}", k),
+        }
+    }
+}
+
+impl std::error::Error for KeyError {}
+
+/// Handling of reserved or invalid keys inserted in `Meta`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum KeyAction {
+    /// Keys are inserted as-is
+    #[default]
+    Allow,
+    /// Reserved keys are prefixed with `RENAME_PREFIX`, invalid keys are normalized
+    ///
+    /// Renamed keys overwriting an existing entry are recorded as `KeyError::Collision`.
+    Rename,
+    /// Entries are dropped
+    Reject,
+    /// Keys are inserted as-is, only recorded
+    Report,
+}
+
+/// Validation policy of `Meta` keys
+///
+/// Every action except `KeyAction::Allow` records the offending keys, see `Meta::key_errors`.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{KeyAction, KeyPolicy, Meta};
+/// let mut meta = Meta::new().with_key_policy(KeyPolicy::new(KeyAction::Rename).with_normalize(true));
+/// meta.insert("level", 1);
+/// meta.insert("blockNumber", 2);
+/// assert_eq!(meta.keys().collect::<Vec<_>>(), vec!["meta_level", "block_number"]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct KeyPolicy {
+    pub action: KeyAction,
+    pub normalize: bool,
+}
+
+impl KeyPolicy {
+    #[inline]
+    #[must_use]
+    pub fn new(action: KeyAction) -> Self {
+        KeyPolicy {
+            action,
+            ..Default::default()
+        }
+    }
+
+    /// Normalize keys with `normalize_key` before validating them
+    #[inline]
+    #[must_use]
+    pub fn with_normalize(self, normalize: bool) -> Self {
+        KeyPolicy { normalize, ..self }
+    }
+
+    /// Key to insert, if any, along with the violation to record
+    pub(crate) fn resolve(&self, k: &str) -> (Option<String>, Option<KeyError>) {
+        let key = match self.normalize {
+            true => normalize_key(k),
+            false => k.to_string(),
+        };
+        if self.action == KeyAction::Allow {
+            return (Some(key), None);
+        }
+        let error = match validate_key(&key) {
+            Ok(()) => return (Some(key), None),
+            Err(error) => error,
+        };
+        let key = match self.action {
+            KeyAction::Reject => None,
+            KeyAction::Rename => match &error {
+                KeyError::Reserved(_) => Some(format!("{}{}", RENAME_PREFIX, key)),
+                _ => Some(normalize_key(&key)).filter(|k| !k.is_empty()),
+            },
+            KeyAction::Allow | KeyAction::Report => Some(key),
+        };
+        (key, Some(error))
+    }
+}
+
+/// Validate a meta key against `RESERVED_KEYS` and the allowed characters
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{validate_key, KeyError};
+/// assert_eq!(validate_key("block.number"), Ok(()));
+/// assert_eq!(validate_key("message"), Err(KeyError::Reserved("message".to_string())));
+/// assert!(validate_key("two words").is_err());
+/// ```
+pub fn validate_key(k: &str) -> Result<(), KeyError> {
+    if RESERVED_KEYS.contains(&k) {
+        return Err(KeyError::Reserved(k.to_string()));
+    }
+    let valid = |c: char| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.');
+    if k.is_empty() || !k.chars().all(valid) {
+        return Err(KeyError::InvalidFormat(k.to_string()));
+    }
+    Ok(())
+}
+
+/// Normalize a meta key to lowercase snake case
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::normalize_key;
+/// assert_eq!(normalize_key("blockNumber"), "block_number");
+/// assert_eq!(normalize_key("Tx Hash"), "tx_hash");
+/// ```
+#[must_use]
+pub fn normalize_key(k: &str) -> String {
+    let mut key = String::with_capacity(k.len());
+    let mut previous: Option<char> = None;
+    for c in k.trim().chars() {
+        match c {
+            c if c.is_ascii_uppercase() => {
+                if matches!(previous, Some(p) if p.is_ascii_lowercase() || p.is_ascii_digit()) {
+                    key.push('_');
+                }
+                key.push(c.to_ascii_lowercase());
+            }
+            c if c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.') => key.push(c),
+            _ => key.push('_'),
+        }
+        previous = Some(c);
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntoValue, Logger, LoggerOperations, MergeError, MergeStrategy, Meta};
+
+    #[test]
+    fn test_validate_key() {
+        assert_eq!(validate_key("pool-id_2.x"), Ok(()));
+        assert_eq!(
+            validate_key("timestamp"),
+            Err(KeyError::Reserved("timestamp".to_string()))
+        );
+        assert_eq!(
+            validate_key(""),
+            Err(KeyError::InvalidFormat("".to_string()))
+        );
+        assert_eq!(
+            validate_key("a/b"),
+            Err(KeyError::InvalidFormat("a/b".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_normalize_key() {
+        assert_eq!(normalize_key("blockNumber"), "block_number");
+        assert_eq!(normalize_key("TxHash"), "tx_hash");
+        assert_eq!(normalize_key("erc20Token"), "erc20_token");
+        assert_eq!(normalize_key(" a/b "), "a_b");
+    }
+
+    #[test]
+    fn test_key_policy_actions() {
+        let mut meta = Meta::new();
+        meta.insert("level", 1);
+        assert_eq!(meta.get("level"), Some(&1.into_value()));
+        assert!(meta.key_errors().is_empty());
+
+        let mut meta = Meta::new().with_key_policy(KeyPolicy::new(KeyAction::Rename));
+        meta.insert("level", 1);
+        meta.insert("a b", 2);
+        assert_eq!(meta.keys().collect::<Vec<_>>(), vec!["meta_level", "a_b"]);
+        assert_eq!(meta.key_errors().len(), 2);
+
+        let mut meta = Meta::new().with_key_policy(KeyPolicy::new(KeyAction::Reject));
+        meta.insert("message", 1);
+        meta.insert_private("service", 2);
+        meta.insert("ok", 3);
+        assert_eq!(meta.keys().collect::<Vec<_>>(), vec!["ok"]);
+        assert_eq!(
+            meta.key_errors(),
+            &[
+                KeyError::Reserved("message".to_string()),
+                KeyError::Reserved("service".to_string())
+            ]
+        );
+
+        let mut meta = Meta::new().with_key_policy(KeyPolicy::new(KeyAction::Report));
+        meta.insert("timestamp", 1);
+        assert_eq!(meta.get("timestamp"), Some(&1.into_value()));
+        assert_eq!(
+            meta.key_errors(),
+            &[KeyError::Reserved("timestamp".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_key_policy_normalize() {
+        let policy = KeyPolicy::new(KeyAction::Reject).with_normalize(true);
+        let mut meta = Meta::from(vec![("Level", 1), ("blockNumber", 2)]).with_key_policy(policy);
+
+        assert_eq!(meta.keys().collect::<Vec<_>>(), vec!["block_number"]);
+        meta.push_to("Tags", "a");
+        assert_eq!(meta.get("tags"), Some(&vec!["a"].into_value()));
+    }
+
+    #[test]
+    fn test_try_insert() {
+        let mut meta = Meta::new();
+        assert_eq!(
+            meta.try_insert("level", 1),
+            Err(KeyError::Reserved("level".to_string()))
+        );
+        assert_eq!(meta.try_insert("pool", 1), Ok(()));
+        assert_eq!(meta.keys().collect::<Vec<_>>(), vec!["pool"]);
+    }
+
+    #[test]
+    fn test_try_with() {
+        let logger = Logger::new("user-service");
+        let meta = |action| {
+            let mut meta = Meta::new().with_key_policy(KeyPolicy::new(action));
+            meta.insert("service", "other");
+            meta
+        };
+        assert_eq!(
+            logger
+                .info("info message")
+                .try_with(meta(KeyAction::Reject)),
+            Err(KeyError::Reserved("service".to_string()))
+        );
+        assert!(logger
+            .info("info message")
+            .try_with(meta(KeyAction::Rename))
+            .is_err());
+        assert!(logger
+            .info("info message")
+            .try_with(meta(KeyAction::Allow))
+            .is_ok());
+        assert!(logger
+            .info("info message")
+            .try_with(Meta::from(vec![("pool", "a")]))
+            .is_ok());
+    }
+
+    #[test]
+    fn test_key_policy_write_paths() {
+        let policy = KeyPolicy::new(KeyAction::Rename);
+        let reserved = Meta::from(vec![("level", "high")]);
+
+        let mut meta = Meta::new().with_key_policy(policy);
+        meta.extend(reserved.to_owned());
+        assert!(meta.get("meta_level").is_some());
+
+        let mut meta = Meta::new().with_key_policy(policy);
+        meta.merge(reserved.to_owned(), MergeStrategy::KeepExisting)
+            .unwrap();
+        assert!(meta.get("meta_level").is_some());
+
+        let mut meta = Meta::new().with_key_policy(policy);
+        meta.insert("meta_level", "low");
+        assert_eq!(
+            meta.merge(reserved.to_owned(), MergeStrategy::Error),
+            Err(MergeError("meta_level".to_string()))
+        );
+
+        let operation = Logger::new("user-service")
+            .info("message")
+            .with(reserved.to_owned().with_key_policy(policy));
+        let meta = Meta::from(&operation);
+        assert!(meta.get("level").is_none());
+        assert!(meta.get("meta_level").is_some());
+    }
+
+    #[test]
+    fn test_key_policy_extend_records_once() {
+        let policy = KeyPolicy::new(KeyAction::Report);
+        let mut b = Meta::new().with_key_policy(policy);
+        b.insert("level", "high");
+
+        let mut a = Meta::new().with_key_policy(policy);
+        a.extend(b.to_owned());
+        assert_eq!(a.key_errors(), &[KeyError::Reserved("level".to_string())]);
+
+        let mut a = Meta::new().with_key_policy(policy);
+        a.merge(b.to_owned(), MergeStrategy::KeepExisting).unwrap();
+        assert_eq!(a.key_errors(), &[KeyError::Reserved("level".to_string())]);
+
+        let mut a = Meta::new().with_key_policy(KeyPolicy::new(KeyAction::Reject));
+        a.extend(b);
+        assert_eq!(
+            a.key_errors(),
+            &[
+                KeyError::Reserved("level".to_string()),
+                KeyError::Reserved("level".to_string())
+            ]
+        );
+        assert!(a.get("level").is_none());
+    }
+
+    #[test]
+    fn test_key_policy_collision() {
+        let mut meta = Meta::new().with_key_policy(KeyPolicy::new(KeyAction::Rename));
+        meta.insert("meta_level", "low");
+        meta.insert("level", "high");
+        assert_eq!(meta.get("meta_level"), Some(&"high".into_value()));
+        assert_eq!(
+            meta.key_errors(),
+            &[
+                KeyError::Reserved("level".to_string()),
+                KeyError::Collision("meta_level".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_logger_key_policy() {
+        let mut log_ops: LoggerOperations = Default::default();
+        let logger = Logger::new("user-service")
+            .with_default_meta(Meta::from(vec![("service", "other")]))
+            .with_key_policy(KeyPolicy::new(KeyAction::Reject));
+        logger.push(
+            &mut log_ops,
+            logger
+                .info("message")
+                .with(Meta::from(vec![("level", "high"), ("pool", "a")])),
+        );
+        let meta = Meta::from(&log_ops.operations[0]);
+        assert_eq!(meta.keys().collect::<Vec<_>>(), vec!["pool"]);
+
+        let mut operation = Logger::new("user-service")
+            .info("message")
+            .with_field("message", "x");
+        assert_eq!(
+            operation.apply_key_policy(KeyPolicy::new(KeyAction::Reject)),
+            vec![KeyError::Reserved("message".to_string())]
+        );
+        assert!(operation.meta.unwrap().fields.is_empty());
+    }
+}
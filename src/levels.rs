@@ -0,0 +1,457 @@
+use crate::{CliLevels, LevelSets, LoggingLevels, NpmLevels};
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+/// Error returned when a level name, level set or priority is not recognized
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseLevelError(pub String);
+
+impl fmt::Display for ParseLevelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid logging level: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseLevelError {}
+
+/// Level from any of the winston level sets
+///
+/// Levels are translated between sets through their syslog equivalent,
+/// which always preserves the ordering from highest to lowest priority:
+///
+/// - npm to syslog: `error` → `error`, `warn` → `warning`, `info` → `info`,
+///   `http`, `verbose`, `debug`, `silly` → `debug`
+/// - cli to syslog: `error` → `error`, `warn` → `warning`, `help`, `data` → `notice`,
+///   `info` → `info`, `debug`, `prompt`, `verbose`, `input`, `silly` → `debug`
+/// - syslog to npm & cli: `emerg`, `alert`, `crit`, `error` → `error`, `warning` → `warn`,
+///   `notice`, `info` → `info`, `debug` → `debug`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Level {
+    Syslog(LoggingLevels),
+    Npm(NpmLevels),
+    Cli(CliLevels),
+}
+
+impl Level {
+    /// Level set this level belongs to
+    #[must_use]
+    pub fn level_set(&self) -> LevelSets {
+        match self {
+            Level::Syslog(_) => LevelSets::Syslog,
+            Level::Npm(_) => LevelSets::Npm,
+            Level::Cli(_) => LevelSets::Cli,
+        }
+    }
+
+    /// Numerical priority within its level set, the lower the more important
+    #[must_use]
+    pub fn priority(&self) -> i32 {
+        match *self {
+            Level::Syslog(level) => level.into(),
+            Level::Npm(level) => level.into(),
+            Level::Cli(level) => level.into(),
+        }
+    }
+
+    /// Winston level name (ex: `"warning"`, `"warn"`)
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Level::Syslog(level) => level.name(),
+            Level::Npm(level) => level.name(),
+            Level::Cli(level) => level.name(),
+        }
+    }
+
+    /// Equivalent RFC5424 syslog level
+    #[must_use]
+    pub fn syslog(&self) -> LoggingLevels {
+        match *self {
+            Level::Syslog(level) => level,
+            Level::Npm(level) => level.into(),
+            Level::Cli(level) => level.into(),
+        }
+    }
+
+    /// Parse winston level name within a level set
+    ///
+    /// Names outside of the level set are parsed as syslog levels and translated.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Level, LevelSets, NpmLevels};
+    /// let level = Level::from_name(LevelSets::Npm, "http");
+    /// assert_eq!(level, Ok(Level::Npm(NpmLevels::Http)));
+    /// ```
+    pub fn from_name(level_set: LevelSets, name: &str) -> Result<Level, ParseLevelError> {
+        let lowercase = name.trim().to_lowercase();
+        let found = match level_set {
+            LevelSets::Syslog => None,
+            LevelSets::Npm => NpmLevels::iter()
+                .find(|level| level.name() == lowercase)
+                .map(Level::Npm),
+            LevelSets::Cli => CliLevels::iter()
+                .find(|level| level.name() == lowercase)
+                .map(Level::Cli),
+        };
+        match found {
+            Some(level) => Ok(level),
+            None => Ok(Level::Syslog(name.parse()?).to_level_set(level_set)),
+        }
+    }
+
+    /// Translate level into another level set
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Level, LevelSets, LoggingLevels, NpmLevels};
+    /// let level = Level::Syslog(LoggingLevels::Warning).to_level_set(LevelSets::Npm);
+    /// assert_eq!(level, Level::Npm(NpmLevels::Warn));
+    /// ```
+    #[must_use]
+    pub fn to_level_set(&self, level_set: LevelSets) -> Level {
+        if self.level_set() == level_set {
+            return *self;
+        }
+        match level_set {
+            LevelSets::Syslog => Level::Syslog(self.syslog()),
+            LevelSets::Npm => Level::Npm(self.syslog().into()),
+            LevelSets::Cli => Level::Cli(self.syslog().into()),
+        }
+    }
+}
+
+impl From<LoggingLevels> for Level {
+    #[inline]
+    fn from(level: LoggingLevels) -> Self {
+        Level::Syslog(level)
+    }
+}
+
+impl From<NpmLevels> for Level {
+    #[inline]
+    fn from(level: NpmLevels) -> Self {
+        Level::Npm(level)
+    }
+}
+
+impl From<CliLevels> for Level {
+    #[inline]
+    fn from(level: CliLevels) -> Self {
+        Level::Cli(level)
+    }
+}
+
+impl LoggingLevels {
+    /// Winston syslog level name
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            LoggingLevels::Emerg => "emerg",
+            LoggingLevels::Alert => "alert",
+            LoggingLevels::Crit => "crit",
+            LoggingLevels::Error => "error",
+            LoggingLevels::Warning => "warning",
+            LoggingLevels::Notice => "notice",
+            LoggingLevels::Info => "info",
+            LoggingLevels::Debug => "debug",
+        }
+    }
+}
+
+impl LoggingLevels {
+    /// RFC5424 numerical priority, from 0 (emerg) to 7 (debug)
+    #[must_use]
+    pub fn priority(&self) -> u8 {
+        *self as u8
+    }
+}
+
+impl fmt::Display for LoggingLevels {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Parse winston level names, case-insensitive
+///
+/// Accepts syslog names and their common aliases (`"warn"`, `"crit"`, `"err"`, ...),
+/// npm and cli names are translated into their syslog equivalent.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::LoggingLevels;
+/// assert_eq!("warn".parse(), Ok(LoggingLevels::Warning));
+/// assert_eq!("critical".parse(), Ok(LoggingLevels::Crit));
+/// assert_eq!("verbose".parse(), Ok(LoggingLevels::Debug));
+/// ```
+impl FromStr for LoggingLevels {
+    type Err = ParseLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "emerg" | "emergency" | "panic" => Ok(LoggingLevels::Emerg),
+            "alert" => Ok(LoggingLevels::Alert),
+            "crit" | "critical" => Ok(LoggingLevels::Crit),
+            "error" | "err" => Ok(LoggingLevels::Error),
+            "warning" | "warn" => Ok(LoggingLevels::Warning),
+            "notice" => Ok(LoggingLevels::Notice),
+            "info" | "informational" => Ok(LoggingLevels::Info),
+            "debug" => Ok(LoggingLevels::Debug),
+            "http" => Ok(NpmLevels::Http.into()),
+            "verbose" => Ok(NpmLevels::Verbose.into()),
+            "silly" => Ok(NpmLevels::Silly.into()),
+            "help" => Ok(CliLevels::Help.into()),
+            "data" => Ok(CliLevels::Data.into()),
+            "prompt" => Ok(CliLevels::Prompt.into()),
+            "input" => Ok(CliLevels::Input.into()),
+            _ => Err(ParseLevelError(s.to_string())),
+        }
+    }
+}
+
+/// ### Example
+/// ```
+/// use substreams_sink_winston::LevelSets;
+/// assert_eq!("npm".parse(), Ok(LevelSets::Npm));
+/// ```
+impl FromStr for LevelSets {
+    type Err = ParseLevelError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "syslog" => Ok(LevelSets::Syslog),
+            "npm" => Ok(LevelSets::Npm),
+            "cli" => Ok(LevelSets::Cli),
+            _ => Err(ParseLevelError(s.to_string())),
+        }
+    }
+}
+
+/// Convert RFC5424 numerical priority, from 0 (emerg) to 7 (debug)
+impl TryFrom<i32> for LoggingLevels {
+    type Error = ParseLevelError;
+
+    fn try_from(priority: i32) -> Result<Self, ParseLevelError> {
+        LoggingLevels::from_i32(priority).ok_or_else(|| ParseLevelError(priority.to_string()))
+    }
+}
+
+impl TryFrom<u8> for LoggingLevels {
+    type Error = ParseLevelError;
+
+    fn try_from(priority: u8) -> Result<Self, ParseLevelError> {
+        LoggingLevels::try_from(i32::from(priority))
+    }
+}
+
+impl From<LoggingLevels> for u8 {
+    #[inline]
+    fn from(level: LoggingLevels) -> Self {
+        level.priority()
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl NpmLevels {
+    fn iter() -> impl Iterator<Item = NpmLevels> {
+        (0..).map_while(NpmLevels::from_i32)
+    }
+
+    /// Winston npm level name
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            NpmLevels::Error => "error",
+            NpmLevels::Warn => "warn",
+            NpmLevels::Info => "info",
+            NpmLevels::Http => "http",
+            NpmLevels::Verbose => "verbose",
+            NpmLevels::Debug => "debug",
+            NpmLevels::Silly => "silly",
+        }
+    }
+}
+
+impl CliLevels {
+    fn iter() -> impl Iterator<Item = CliLevels> {
+        (0..).map_while(CliLevels::from_i32)
+    }
+
+    /// Winston cli level name
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            CliLevels::Error => "error",
+            CliLevels::Warn => "warn",
+            CliLevels::Help => "help",
+            CliLevels::Data => "data",
+            CliLevels::Info => "info",
+            CliLevels::Debug => "debug",
+            CliLevels::Prompt => "prompt",
+            CliLevels::Verbose => "verbose",
+            CliLevels::Input => "input",
+            CliLevels::Silly => "silly",
+        }
+    }
+}
+
+impl From<NpmLevels> for LoggingLevels {
+    #[inline]
+    fn from(level: NpmLevels) -> Self {
+        match level {
+            NpmLevels::Error => LoggingLevels::Error,
+            NpmLevels::Warn => LoggingLevels::Warning,
+            NpmLevels::Info => LoggingLevels::Info,
+            NpmLevels::Http | NpmLevels::Verbose | NpmLevels::Debug | NpmLevels::Silly => {
+                LoggingLevels::Debug
+            }
+        }
+    }
+}
+
+impl From<CliLevels> for LoggingLevels {
+    #[inline]
+    fn from(level: CliLevels) -> Self {
+        match level {
+            CliLevels::Error => LoggingLevels::Error,
+            CliLevels::Warn => LoggingLevels::Warning,
+            CliLevels::Help | CliLevels::Data => LoggingLevels::Notice,
+            CliLevels::Info => LoggingLevels::Info,
+            CliLevels::Debug
+            | CliLevels::Prompt
+            | CliLevels::Verbose
+            | CliLevels::Input
+            | CliLevels::Silly => LoggingLevels::Debug,
+        }
+    }
+}
+
+impl From<LoggingLevels> for NpmLevels {
+    #[inline]
+    fn from(level: LoggingLevels) -> Self {
+        match level {
+            LoggingLevels::Emerg
+            | LoggingLevels::Alert
+            | LoggingLevels::Crit
+            | LoggingLevels::Error => NpmLevels::Error,
+            LoggingLevels::Warning => NpmLevels::Warn,
+            LoggingLevels::Notice | LoggingLevels::Info => NpmLevels::Info,
+            LoggingLevels::Debug => NpmLevels::Debug,
+        }
+    }
+}
+
+impl From<LoggingLevels> for CliLevels {
+    #[inline]
+    fn from(level: LoggingLevels) -> Self {
+        match level {
+            LoggingLevels::Emerg
+            | LoggingLevels::Alert
+            | LoggingLevels::Crit
+            | LoggingLevels::Error => CliLevels::Error,
+            LoggingLevels::Warning => CliLevels::Warn,
+            LoggingLevels::Notice | LoggingLevels::Info => CliLevels::Info,
+            LoggingLevels::Debug => CliLevels::Debug,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_level_names() {
+        assert_eq!(Level::from(LoggingLevels::Warning).name(), "warning");
+        assert_eq!(Level::from(NpmLevels::Warn).name(), "warn");
+        assert_eq!(Level::from(CliLevels::Prompt).name(), "prompt");
+    }
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!("WARNING".parse(), Ok(LoggingLevels::Warning));
+        assert_eq!(" crit ".parse(), Ok(LoggingLevels::Crit));
+        assert_eq!("silly".parse(), Ok(LoggingLevels::Debug));
+        assert_eq!(
+            "unknown".parse::<LoggingLevels>(),
+            Err(ParseLevelError("unknown".to_string()))
+        );
+        assert_eq!(LoggingLevels::Notice.to_string(), "notice");
+    }
+
+    #[test]
+    fn test_level_from_name() {
+        assert_eq!(
+            Level::from_name(LevelSets::Cli, "Verbose"),
+            Ok(Level::Cli(CliLevels::Verbose))
+        );
+        assert_eq!(
+            Level::from_name(LevelSets::Npm, "crit"),
+            Ok(Level::Npm(NpmLevels::Error))
+        );
+        assert_eq!(
+            Level::from_name(LevelSets::Syslog, "http"),
+            Ok(Level::Syslog(LoggingLevels::Debug))
+        );
+        assert!(Level::from_name(LevelSets::Npm, "unknown").is_err());
+    }
+
+    #[test]
+    fn test_level_priority() {
+        assert_eq!(LoggingLevels::try_from(3), Ok(LoggingLevels::Error));
+        assert_eq!(LoggingLevels::try_from(7u8), Ok(LoggingLevels::Debug));
+        assert!(LoggingLevels::try_from(8).is_err());
+        assert_eq!(LoggingLevels::Warning.priority(), 4);
+    }
+
+    #[test]
+    fn test_level_set_mapping() {
+        let level = Level::from(CliLevels::Help);
+        assert_eq!(level.syslog(), LoggingLevels::Notice);
+        assert_eq!(
+            level.to_level_set(LevelSets::Npm),
+            Level::Npm(NpmLevels::Info)
+        );
+        assert_eq!(
+            Level::from(NpmLevels::Silly).to_level_set(LevelSets::Cli),
+            Level::Cli(CliLevels::Debug)
+        );
+        assert_eq!(level.to_level_set(LevelSets::Cli), level);
+    }
+
+    #[test]
+    fn test_level_set_mapping_preserves_order() {
+        let npm = [
+            NpmLevels::Error,
+            NpmLevels::Warn,
+            NpmLevels::Info,
+            NpmLevels::Http,
+            NpmLevels::Verbose,
+            NpmLevels::Debug,
+            NpmLevels::Silly,
+        ];
+        for pair in npm.windows(2) {
+            assert!(LoggingLevels::from(pair[0]) <= LoggingLevels::from(pair[1]));
+        }
+        let cli = [
+            CliLevels::Error,
+            CliLevels::Warn,
+            CliLevels::Help,
+            CliLevels::Data,
+            CliLevels::Info,
+            CliLevels::Debug,
+            CliLevels::Prompt,
+            CliLevels::Verbose,
+            CliLevels::Input,
+            CliLevels::Silly,
+        ];
+        for pair in cli.windows(2) {
+            assert!(LoggingLevels::from(pair[0]) <= LoggingLevels::from(pair[1]));
+        }
+    }
+}
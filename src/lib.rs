@@ -10,23 +10,60 @@
 //! // ==============
 //! let mut logger = Logger::new("user-service");
 //!
+//! // Push through the Logger, skipping operations below its level (see `Logger::with_level`)
+//!
 //! // Informational: informational messages
-//! log_ops.push(logger.info("message"));
+//! logger.push(&mut log_ops, logger.info("message"));
 //!
 //! // Error: error conditions
-//! log_ops.push(logger.error("error message"));
+//! logger.push(&mut log_ops, logger.error("error message"));
 //!
 //! // Include Metadata
 //! let meta = Meta::from(vec!(["key", "value"]));
-//! log_ops.push(logger.info("message").with(meta));
+//! logger.push(&mut log_ops, logger.info("message").with(meta));
 //! ```
+extern crate self as substreams_sink_winston;
+
 #[path = "pb/pinax.substreams.sink.winston.v1.rs"]
 #[allow(dead_code)]
 pub mod pb;
 pub use self::pb::*;
 
+mod bip39;
+mod error;
+mod filter;
+mod fingerprint;
 mod helpers;
+mod keys;
+pub use self::keys::*;
+mod levels;
+pub use self::levels::*;
+mod limits;
+pub use self::limits::*;
+mod macros;
 mod meta;
 pub use self::meta::*;
+mod value;
+pub use self::value::*;
 mod logger;
 pub use self::logger::*;
+mod params;
+pub use self::params::*;
+mod provenance;
+mod redact;
+pub use self::redact::*;
+mod scalar;
+pub use self::scalar::*;
+#[cfg(feature = "serde")]
+mod ser;
+#[cfg(feature = "serde")]
+pub use self::ser::*;
+mod template;
+pub use self::template::*;
+mod to_meta;
+pub use self::to_meta::*;
+mod trace;
+pub use self::trace::*;
+
+#[cfg(feature = "derive")]
+pub use substreams_sink_winston_derive::ToMeta;
@@ -0,0 +1,329 @@
+use crate::{IntoValue, LoggerOperation, LoggerOperations, Meta};
+use prost::Message;
+
+/// Meta key marking what was cut by `LoggerOperation::truncate`
+///
+/// Prefixed with `_` to stay clear of user metadata, a `truncated` entry is ordinary data.
+pub const TRUNCATED_KEY: &str = "_truncated";
+
+/// Suffix of truncated messages
+pub const TRUNCATED_SUFFIX: &str = "…";
+
+/// Size budget of a `LoggerOperation`, unset limits are not enforced
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::SizeLimits;
+/// let limits = SizeLimits::new()
+///     .with_max_message_len(1024)
+///     .with_max_meta_entries(32)
+///     .with_max_encoded_len(16 * 1024);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct SizeLimits {
+    /// Maximum `message` length, in bytes
+    pub max_message_len: Option<usize>,
+    /// Maximum number of `meta` entries, the `TRUNCATED_KEY` marker written by truncation excluded
+    pub max_meta_entries: Option<usize>,
+    /// Maximum protobuf encoded length of the operation, in bytes
+    pub max_encoded_len: Option<usize>,
+}
+
+impl SizeLimits {
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        SizeLimits::default()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_max_message_len(self, max_message_len: usize) -> Self {
+        SizeLimits {
+            max_message_len: Some(max_message_len),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_max_meta_entries(self, max_meta_entries: usize) -> Self {
+        SizeLimits {
+            max_meta_entries: Some(max_meta_entries),
+            ..self
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_max_encoded_len(self, max_encoded_len: usize) -> Self {
+        SizeLimits {
+            max_encoded_len: Some(max_encoded_len),
+            ..self
+        }
+    }
+}
+
+impl LoggerOperation {
+    /// Truncate the operation to fit `limits`
+    ///
+    /// Messages are cut on a character boundary and end with `TRUNCATED_SUFFIX`.
+    /// Meta entries beyond `max_meta_entries` are dropped in reverse key order, the
+    /// `google.protobuf.Struct` meta of an operation not keeping insertion order, then the
+    /// largest entries are dropped until the operation fits `max_encoded_len`, shortening
+    /// the message as a last resort. What was cut is recorded under `TRUNCATED_KEY`, holding
+    /// the original `message` length and the dropped `meta` keys. An existing `TRUNCATED_KEY`
+    /// entry is counted as data, and dropped and recorded when the marker replaces it.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, SizeLimits};
+    /// let logger = Logger::new("user-service");
+    /// let mut operation = logger.info("a very long message");
+    /// operation.truncate(&SizeLimits::new().with_max_message_len(10));
+    /// assert_eq!(operation.message, "a very …");
+    /// ```
+    pub fn truncate(&mut self, limits: &SizeLimits) {
+        let mut truncation = Truncation::default();
+        if let Some(max) = limits.max_message_len {
+            truncation.cut_message(self, max);
+        }
+        if let Some(max) = limits.max_meta_entries {
+            while truncation.meta_keys(self).len() > max {
+                match truncation.meta_keys(self).pop() {
+                    Some(k) => truncation.drop_meta(self, &k),
+                    None => break,
+                }
+            }
+        }
+        truncation.mark(self);
+        if let Some(max) = limits.max_encoded_len {
+            while self.encoded_len() > max {
+                match truncation.largest_meta_key(self) {
+                    Some(k) => truncation.drop_meta(self, &k),
+                    None => break,
+                }
+                truncation.mark(self);
+            }
+            while self.encoded_len() > max && !self.message.is_empty() {
+                let excess = self.encoded_len() - max;
+                let len = self.message.len().saturating_sub(excess);
+                truncation.cut_message(self, len);
+                truncation.mark(self);
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Truncation {
+    message_len: Option<usize>,
+    meta: Vec<String>,
+    marked: bool,
+}
+
+impl Truncation {
+    /// Typed and legacy meta keys, sorted, the `TRUNCATED_KEY` marker excluded once written
+    fn meta_keys(&self, operation: &LoggerOperation) -> Vec<String> {
+        let mut keys: Vec<String> = operation
+            .meta
+            .iter()
+            .flat_map(|meta| meta.fields.keys())
+            .chain(operation.legacy_meta.keys())
+            .filter(|k| !self.marked || k.as_str() != TRUNCATED_KEY)
+            .cloned()
+            .collect();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    /// Key of the meta entry with the largest encoded length, the last key on ties
+    fn largest_meta_key(&self, operation: &LoggerOperation) -> Option<String> {
+        let size = |k: &String| {
+            let typed = operation.meta.as_ref().and_then(|meta| meta.fields.get(k));
+            typed.map_or(0, Message::encoded_len)
+                + operation.legacy_meta.get(k).map_or(0, String::len)
+                + k.len()
+        };
+        self.meta_keys(operation).into_iter().max_by_key(size)
+    }
+
+    fn cut_message(&mut self, operation: &mut LoggerOperation, max: usize) {
+        if operation.message.len() <= max {
+            return;
+        }
+        self.message_len.get_or_insert(operation.message.len());
+        let message = operation
+            .message
+            .strip_suffix(TRUNCATED_SUFFIX)
+            .unwrap_or(&operation.message);
+        let mut len = max
+            .saturating_sub(TRUNCATED_SUFFIX.len())
+            .min(message.len());
+        while !message.is_char_boundary(len) {
+            len -= 1;
+        }
+        operation.message = match max < TRUNCATED_SUFFIX.len() {
+            true => String::new(),
+            false => format!("{}{}", &message[..len], TRUNCATED_SUFFIX),
+        };
+    }
+
+    fn drop_meta(&mut self, operation: &mut LoggerOperation, k: &str) {
+        if let Some(meta) = &mut operation.meta {
+            meta.fields.remove(k);
+        }
+        operation.legacy_meta.remove(k);
+        operation.private_keys.retain(|key| key != k);
+        self.meta.push(k.to_string());
+    }
+
+    fn mark(&mut self, operation: &mut LoggerOperation) {
+        if self.message_len.is_none() && self.meta.is_empty() {
+            return;
+        }
+        if !self.marked && self.meta_keys(operation).iter().any(|k| k == TRUNCATED_KEY) {
+            self.drop_meta(operation, TRUNCATED_KEY);
+        }
+        self.marked = true;
+        let mut marker = Meta::new();
+        if let Some(len) = self.message_len {
+            marker.insert("message", len);
+        }
+        if !self.meta.is_empty() {
+            marker.insert("meta", self.meta.to_owned());
+        }
+        operation
+            .meta
+            .get_or_insert_with(Default::default)
+            .fields
+            .insert(TRUNCATED_KEY.to_string(), marker.into_value());
+    }
+}
+
+impl LoggerOperations {
+    /// Truncate every operation to fit `limits`, see `LoggerOperation::truncate`
+    pub fn truncate(&mut self, limits: &SizeLimits) {
+        for operation in &mut self.operations {
+            operation.truncate(limits);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn marker(operation: &LoggerOperation) -> Option<Meta> {
+        Meta::from(operation)
+            .get(TRUNCATED_KEY)
+            .and_then(|value| match &value.kind {
+                Some(prost_types::value::Kind::StructValue(object)) => {
+                    Some(Meta::from(object.to_owned()))
+                }
+                _ => None,
+            })
+    }
+
+    #[test]
+    fn test_truncate_message() {
+        let logger = crate::Logger::new("user-service");
+        let mut operation = logger.info("héllo world");
+        operation.truncate(&SizeLimits::new().with_max_message_len(6));
+
+        assert_eq!(operation.message, "hé…");
+        assert_eq!(
+            marker(&operation).unwrap().get("message"),
+            Some(&12.into_value())
+        );
+
+        let mut operation = logger.info("short");
+        operation.truncate(&SizeLimits::new().with_max_message_len(6));
+        assert_eq!(operation.message, "short");
+        assert!(operation.meta.is_none());
+    }
+
+    #[test]
+    fn test_truncate_meta_entries() {
+        let logger = crate::Logger::new("user-service");
+        let mut meta = Meta::from(vec![("a", 1), ("b", 2), ("c", 3)]);
+        meta.insert_private("d", 4);
+        let mut operation = logger.info("message").with(meta);
+        operation.truncate(&SizeLimits::new().with_max_meta_entries(2));
+
+        let meta = Meta::from(&operation);
+        assert_eq!(
+            meta.keys().collect::<Vec<_>>(),
+            vec![TRUNCATED_KEY, "a", "b"]
+        );
+        assert_eq!(
+            marker(&operation).unwrap().get("meta"),
+            Some(&vec!["d", "c"].into_value())
+        );
+        assert!(operation.private_keys.is_empty());
+    }
+
+    #[test]
+    fn test_truncate_existing_marker_key() {
+        let logger = crate::Logger::new("user-service");
+        let meta = Meta::from(vec![("a", 1), ("truncated", 2)]);
+        let mut operation = logger.info("message").with(meta);
+        operation.truncate(&SizeLimits::new().with_max_meta_entries(1));
+
+        let meta = Meta::from(&operation);
+        assert_eq!(meta.keys().collect::<Vec<_>>(), vec![TRUNCATED_KEY, "a"]);
+        assert_eq!(
+            marker(&operation).unwrap().get("meta"),
+            Some(&vec!["truncated"].into_value())
+        );
+
+        let meta = Meta::from(vec![("a", 1), (TRUNCATED_KEY, 2)]);
+        let mut operation = logger.info("a long message").with(meta);
+        operation.truncate(&SizeLimits::new().with_max_message_len(8));
+
+        assert_eq!(Meta::from(&operation).get("a"), Some(&1.into_value()));
+        assert_eq!(
+            marker(&operation).unwrap().get("meta"),
+            Some(&vec![TRUNCATED_KEY].into_value())
+        );
+    }
+
+    #[test]
+    fn test_truncate_encoded_len() {
+        let logger = crate::Logger::new("user-service");
+        let mut operation = logger.info("message").with(Meta::from(vec![
+            ("large", "x".repeat(1000)),
+            ("small", "y".to_string()),
+        ]));
+        operation.truncate(&SizeLimits::new().with_max_encoded_len(200));
+
+        assert!(operation.encoded_len() <= 200);
+        assert_eq!(operation.message, "message");
+        assert_eq!(
+            marker(&operation).unwrap().get("meta"),
+            Some(&vec!["large"].into_value())
+        );
+
+        let mut operation = logger.info(&"m".repeat(1000));
+        operation.truncate(&SizeLimits::new().with_max_encoded_len(200));
+        assert!(operation.encoded_len() <= 200);
+        assert!(operation.message.ends_with(TRUNCATED_SUFFIX));
+        assert_eq!(
+            marker(&operation).unwrap().get("message"),
+            Some(&1000.into_value())
+        );
+    }
+
+    #[test]
+    fn test_truncate_operations() {
+        let logger = crate::Logger::new("user-service");
+        let mut log_ops: LoggerOperations = Default::default();
+        log_ops.push(logger.info("first message"));
+        log_ops.push(logger.info("second message"));
+        log_ops.truncate(&SizeLimits::new().with_max_message_len(8));
+
+        assert_eq!(log_ops.operations[0].message, "first…");
+        assert_eq!(log_ops.operations[1].message, "secon…");
+    }
+}
@@ -1,8 +1,16 @@
-use crate::{LoggerOperation, LoggingLevels};
+use crate::{
+    Block, CliLevels, KeyPolicy, Level, LevelSets, LoggerOperation, LoggerOperations,
+    LoggingLevels, Meta, NpmLevels,
+};
 
-#[derive(Eq, Debug, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct Logger {
     pub service: String,
+    pub block: Option<Block>,
+    pub default_meta: Meta,
+    pub level_set: LevelSets,
+    pub level: Option<Level>,
+    pub key_policy: KeyPolicy,
 }
 
 impl Logger {
@@ -18,28 +26,270 @@ impl Logger {
     pub fn new(service: &str) -> Self {
         Self {
             service: service.to_string(),
+            block: None,
+            default_meta: Meta::new(),
+            level_set: LevelSets::Syslog,
+            level: None,
+            key_policy: KeyPolicy::default(),
         }
     }
 
-    /// Emergency: system is unusable
+    /// Set minimum level, operations below it are never pushed by `Logger::push`
     ///
     /// ### Example
     /// ```
-    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// use substreams_sink_winston::{Logger, LoggerOperations, LoggingLevels};
     /// let mut log_ops: LoggerOperations = Default::default();
-    /// let mut logger = Logger::new("user-service");
-    /// log_ops.push(logger.emerg("emergy message"));
+    /// let mut logger = Logger::new("user-service").with_level(LoggingLevels::Warning);
+    /// logger.push(&mut log_ops, logger.debug("debug message"));
+    /// logger.push(&mut log_ops, logger.error("error message"));
+    /// assert_eq!(log_ops.operations.len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_level<L: Into<Level>>(self, level: L) -> Self {
+        Logger {
+            level: Some(level.into()),
+            ..self
+        }
+    }
+
+    /// Check whether operations at this level would be emitted
+    ///
+    /// Useful to skip building expensive messages or metadata.
+    #[must_use]
+    pub fn is_level_enabled<L: Into<Level>>(&self, level: L) -> bool {
+        match self.level {
+            None => true,
+            Some(threshold) => {
+                let level = level.into().to_level_set(self.level_set);
+                level.priority() <= threshold.to_level_set(self.level_set).priority()
+            }
+        }
+    }
+
+    /// Push operation into `LoggerOperations`, unless it is below the Logger level threshold
+    ///
+    /// Metadata keys are validated with the Logger `KeyPolicy`, see `Logger::with_key_policy`.
+    pub fn push(&self, log_ops: &mut LoggerOperations, operation: LoggerOperation) {
+        let mut operation = operation;
+        if !self.is_operation_enabled(&operation) {
+            return;
+        }
+        if self.key_policy != KeyPolicy::default() {
+            operation.apply_key_policy(self.key_policy);
+        }
+        log_ops.push(operation);
+    }
+
+    /// Push operations into `LoggerOperations`, skipping those below the Logger level threshold
+    pub fn extend(&self, log_ops: &mut LoggerOperations, operations: Vec<LoggerOperation>) {
+        for operation in operations {
+            self.push(log_ops, operation);
+        }
+    }
+
+    fn is_operation_enabled(&self, operation: &LoggerOperation) -> bool {
+        let level = LevelSets::from_i32(operation.level_set)
+            .and_then(|level_set| Level::from_name(level_set, &operation.level_name).ok())
+            .unwrap_or_else(|| operation.level().into());
+        self.is_level_enabled(level)
+    }
+
+    /// Set winston level set used by consumers (syslog, npm or cli)
+    ///
+    /// Operations always include the equivalent syslog `level`,
+    /// along with the `level_name` translated into the Logger level set.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{LevelSets, Logger};
+    /// let logger = Logger::new("user-service").with_level_set(LevelSets::Npm);
+    /// assert_eq!(logger.warn("message").level_name, "warn");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_level_set(self, level_set: LevelSets) -> Self {
+        Logger { level_set, ..self }
+    }
+
+    /// Validate default metadata and the metadata of operations pushed with `Logger::push`
+    ///
+    /// Rejected keys are dropped and renamed keys rewritten, use
+    /// `LoggerOperation::apply_key_policy` to inspect the violations.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{KeyAction, KeyPolicy, Logger, LoggerOperations, Meta};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service").with_key_policy(KeyPolicy::new(KeyAction::Rename));
+    /// logger.push(&mut log_ops, logger.info("message").with_field("level", "high"));
+    /// let meta = Meta::from(&log_ops.operations[0]);
+    /// assert!(meta.get("meta_level").is_some());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_key_policy(self, key_policy: KeyPolicy) -> Self {
+        Logger {
+            default_meta: self.default_meta.with_key_policy(key_policy),
+            key_policy,
+            ..self
+        }
+    }
+
+    /// Set default metadata merged into every operation
+    ///
+    /// Metadata set with `LoggerOperation::with` takes precedence on key conflicts.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, Meta};
+    /// let meta = Meta::from(vec!(["network", "mainnet"]));
+    /// let logger = Logger::new("user-service").with_default_meta(meta);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_default_meta(self, meta: Meta) -> Self {
+        Logger {
+            default_meta: meta,
+            ..self
+        }
+    }
+
+    /// Create child Logger inheriting service, block context and default metadata
+    ///
+    /// Metadata of the child takes precedence over the parent default metadata on key conflicts.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, Meta};
+    /// let logger = Logger::new("user-service");
+    /// let child = logger.child(Meta::from(vec!(["pool", "abc"])));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn child(&self, meta: Meta) -> Self {
+        let mut default_meta = self.default_meta.to_owned();
+        default_meta.extend(meta);
+        Logger {
+            default_meta,
+            ..self.to_owned()
+        }
+    }
+
+    /// Create child Logger with a sub-service name appended to the parent service
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, Meta};
+    /// let logger = Logger::new("dex");
+    /// let child = logger.child_service("swaps", Meta::new());
+    /// assert_eq!(child.service, "dex.swaps");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn child_service(&self, service: &str, meta: Meta) -> Self {
+        Logger {
+            service: format!("{}.{}", self.service, service),
+            ..self.child(meta)
+        }
+    }
+
+    /// Stamp every operation with the block context of a Substreams `Clock`
+    ///
+    /// Takes a `Block`, or a `substreams::pb::substreams::Clock` with the `substreams` feature.
+    /// The parent hash, which is not part of the `Clock`, is kept only when it was set
+    /// for the same block, re-clocking a reused Logger never attaches a stale parent hash.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Block, Logger};
+    /// let block = Block { number: 1, hash: "abc".to_string(), ..Default::default() };
+    /// let logger = Logger::new("user-service").with_clock(&block);
+    /// assert_eq!(logger.info("message").block.unwrap().number, 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_clock<B: Into<Block>>(self, clock: B) -> Self {
+        let mut block = clock.into();
+        if let Some(previous) = self.block {
+            if block.parent_hash.is_empty()
+                && previous.number == block.number
+                && previous.hash == block.hash
+            {
+                block.parent_hash = previous.parent_hash;
+            }
+        }
+        Logger {
+            block: Some(block),
+            ..self
+        }
+    }
+
+    /// Set parent block hash, which is not part of the Substreams `Clock`
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::Logger;
+    /// let logger = Logger::new("user-service").with_parent_hash("abc");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_parent_hash(self, parent_hash: &str) -> Self {
+        let mut block = self.block.unwrap_or_default();
+        block.parent_hash = parent_hash.to_string();
+        Logger {
+            block: Some(block),
+            ..self
+        }
+    }
+
+    /// Log message at a level picked at runtime
+    ///
+    /// Accepts levels from any level set, translated into the Logger level set.
+    /// Operations are built whatever the Logger level, `Logger::push` skips those below it.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations, LoggingLevels};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// let level: LoggingLevels = "warn".parse().unwrap();
+    /// logger.push(&mut log_ops, logger.log(level, "message"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn emerg(&mut self, message: &str) -> LoggerOperation {
-        LoggerOperation {
-            level: LoggingLevels::Emerg.into(),
+    pub fn log<L: Into<Level>>(&self, level: L, message: &str) -> LoggerOperation {
+        let level = level.into().to_level_set(self.level_set);
+        let operation = LoggerOperation {
+            level: level.syslog().into(),
+            level_set: level.level_set().into(),
+            level_name: level.name().to_string(),
             message: message.to_string(),
-            meta: Default::default(),
             service: self.service.to_owned(),
+            block: self.block.to_owned(),
+            ..Default::default()
+        };
+        match self.default_meta.is_empty() {
+            true => operation,
+            false => operation.with(self.default_meta.to_owned()),
         }
     }
+
+    /// Emergency: system is unusable
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let mut logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.emerg("emergy message"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn emerg(&self, message: &str) -> LoggerOperation {
+        self.log(LoggingLevels::Emerg, message)
+    }
     /// Alert: action must be taken immediately
     ///
     /// ### Example
@@ -47,17 +297,12 @@ impl Logger {
     /// use substreams_sink_winston::{Logger, LoggerOperations};
     /// let mut log_ops: LoggerOperations = Default::default();
     /// let mut logger = Logger::new("user-service");
-    /// log_ops.push(logger.alert("alert message"));
+    /// logger.push(&mut log_ops, logger.alert("alert message"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn alert(&mut self, message: &str) -> LoggerOperation {
-        LoggerOperation {
-            level: LoggingLevels::Alert.into(),
-            message: message.to_string(),
-            meta: Default::default(),
-            service: self.service.to_owned(),
-        }
+    pub fn alert(&self, message: &str) -> LoggerOperation {
+        self.log(LoggingLevels::Alert, message)
     }
     /// Critical: critical conditions
     ///
@@ -66,17 +311,12 @@ impl Logger {
     /// use substreams_sink_winston::{Logger, LoggerOperations};
     /// let mut log_ops: LoggerOperations = Default::default();
     /// let mut logger = Logger::new("user-service");
-    /// log_ops.push(logger.crit("message"));
+    /// logger.push(&mut log_ops, logger.crit("message"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn crit(&mut self, message: &str) -> LoggerOperation {
-        LoggerOperation {
-            level: LoggingLevels::Crit.into(),
-            message: message.to_string(),
-            meta: Default::default(),
-            service: self.service.to_owned(),
-        }
+    pub fn crit(&self, message: &str) -> LoggerOperation {
+        self.log(LoggingLevels::Crit, message)
     }
 
     /// Error: error conditions
@@ -86,17 +326,12 @@ impl Logger {
     /// use substreams_sink_winston::{Logger, LoggerOperations};
     /// let mut log_ops: LoggerOperations = Default::default();
     /// let mut logger = Logger::new("user-service");
-    /// log_ops.push(logger.error("message"));
+    /// logger.push(&mut log_ops, logger.error("message"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn error(&mut self, message: &str) -> LoggerOperation {
-        LoggerOperation {
-            level: LoggingLevels::Error.into(),
-            message: message.to_string(),
-            meta: Default::default(),
-            service: self.service.to_owned(),
-        }
+    pub fn error(&self, message: &str) -> LoggerOperation {
+        self.log(LoggingLevels::Error, message)
     }
 
     /// Warning: warning conditions
@@ -106,17 +341,12 @@ impl Logger {
     /// use substreams_sink_winston::{Logger, LoggerOperations};
     /// let mut log_ops: LoggerOperations = Default::default();
     /// let mut logger = Logger::new("user-service");
-    /// log_ops.push(logger.warning("message"));
+    /// logger.push(&mut log_ops, logger.warning("message"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn warning(&mut self, message: &str) -> LoggerOperation {
-        LoggerOperation {
-            level: LoggingLevels::Warning.into(),
-            message: message.to_string(),
-            meta: Default::default(),
-            service: self.service.to_owned(),
-        }
+    pub fn warning(&self, message: &str) -> LoggerOperation {
+        self.log(LoggingLevels::Warning, message)
     }
 
     /// Notice: normal but significant condition
@@ -126,17 +356,12 @@ impl Logger {
     /// use substreams_sink_winston::{Logger, LoggerOperations};
     /// let mut log_ops: LoggerOperations = Default::default();
     /// let mut logger = Logger::new("user-service");
-    /// log_ops.push(logger.notice("message"));
+    /// logger.push(&mut log_ops, logger.notice("message"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn notice(&mut self, message: &str) -> LoggerOperation {
-        LoggerOperation {
-            level: LoggingLevels::Notice.into(),
-            message: message.to_string(),
-            meta: Default::default(),
-            service: self.service.to_owned(),
-        }
+    pub fn notice(&self, message: &str) -> LoggerOperation {
+        self.log(LoggingLevels::Notice, message)
     }
 
     /// Informational: informational messages
@@ -146,17 +371,12 @@ impl Logger {
     /// use substreams_sink_winston::{Logger, LoggerOperations};
     /// let mut log_ops: LoggerOperations = Default::default();
     /// let mut logger = Logger::new("user-service");
-    /// log_ops.push(logger.info("message"));
+    /// logger.push(&mut log_ops, logger.info("message"));
     /// ```
     #[inline]
     #[must_use]
     pub fn info(&self, message: &str) -> LoggerOperation {
-        LoggerOperation {
-            level: LoggingLevels::Info.into(),
-            message: message.to_string(),
-            meta: Default::default(),
-            service: self.service.to_owned(),
-        }
+        self.log(LoggingLevels::Info, message)
     }
 
     /// Debug: debug-level messages
@@ -166,32 +386,332 @@ impl Logger {
     /// use substreams_sink_winston::{Logger, LoggerOperations};
     /// let mut log_ops: LoggerOperations = Default::default();
     /// let mut logger = Logger::new("user-service");
-    /// log_ops.push(logger.debug("message"));
+    /// logger.push(&mut log_ops, logger.debug("message"));
     /// ```
     #[inline]
     #[must_use]
-    pub fn debug(&mut self, message: &str) -> LoggerOperation {
-        LoggerOperation {
-            level: LoggingLevels::Debug.into(),
-            message: message.to_string(),
-            meta: Default::default(),
-            service: self.service.to_owned(),
+    pub fn debug(&self, message: &str) -> LoggerOperation {
+        self.log(LoggingLevels::Debug, message)
+    }
+
+    /// Warn (npm, cli): warning conditions
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.warn("message"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn warn(&self, message: &str) -> LoggerOperation {
+        self.log(NpmLevels::Warn, message)
+    }
+
+    /// Http (npm): HTTP request messages
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.http("message"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn http(&self, message: &str) -> LoggerOperation {
+        self.log(NpmLevels::Http, message)
+    }
+
+    /// Verbose (npm, cli): verbose messages
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.verbose("message"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn verbose(&self, message: &str) -> LoggerOperation {
+        self.log(NpmLevels::Verbose, message)
+    }
+
+    /// Silly (npm, cli): lowest priority messages
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.silly("message"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn silly(&self, message: &str) -> LoggerOperation {
+        self.log(NpmLevels::Silly, message)
+    }
+
+    /// Help (cli): help messages
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.help("message"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn help(&self, message: &str) -> LoggerOperation {
+        self.log(CliLevels::Help, message)
+    }
+
+    /// Data (cli): data messages
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.data("message"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn data(&self, message: &str) -> LoggerOperation {
+        self.log(CliLevels::Data, message)
+    }
+
+    /// Prompt (cli): prompt messages
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.prompt("message"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn prompt(&self, message: &str) -> LoggerOperation {
+        self.log(CliLevels::Prompt, message)
+    }
+
+    /// Input (cli): input messages
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.input("message"));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn input(&self, message: &str) -> LoggerOperation {
+        self.log(CliLevels::Input, message)
+    }
+}
+
+impl From<&Block> for Block {
+    #[inline]
+    fn from(block: &Block) -> Self {
+        block.to_owned()
+    }
+}
+
+/// Block context of a Substreams `Clock`, the `Clock` id being the block hash
+///
+/// ### Example
+/// ```
+/// use substreams::pb::substreams::Clock;
+/// use substreams_sink_winston::Logger;
+/// let clock = Clock { id: "abc".to_string(), number: 1, timestamp: None };
+/// let logger = Logger::new("user-service").with_clock(&clock);
+/// assert_eq!(logger.info("message").block.unwrap().hash, "abc");
+/// ```
+#[cfg(feature = "substreams")]
+impl From<&substreams::pb::substreams::Clock> for Block {
+    #[inline]
+    fn from(clock: &substreams::pb::substreams::Clock) -> Self {
+        Block {
+            hash: clock.id.to_owned(),
+            number: clock.number,
+            timestamp: clock.timestamp.to_owned(),
+            ..Default::default()
         }
     }
 }
 
+#[cfg(feature = "substreams")]
+impl From<substreams::pb::substreams::Clock> for Block {
+    #[inline]
+    fn from(clock: substreams::pb::substreams::Clock) -> Self {
+        Block::from(&clock)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::LoggerOperations;
+    use crate::{IntoValue, LoggerOperations};
 
     #[test]
     fn test_counter() {
         let mut log_ops: LoggerOperations = Default::default();
-        let mut logger = Logger::new("user-service");
+        let logger = Logger::new("user-service");
 
         log_ops.push(logger.info("info message"));
         log_ops.push(logger.warning("warning"));
         assert_eq!(log_ops.operations.len(), 2);
     }
+
+    #[test]
+    fn test_with_clock() {
+        let clock = Block {
+            hash: "abc".to_string(),
+            number: 123,
+            timestamp: Some(prost_types::Timestamp {
+                seconds: 1677000000,
+                nanos: 0,
+            }),
+            ..Default::default()
+        };
+        let logger = Logger::new("user-service")
+            .with_clock(&clock)
+            .with_parent_hash("parent")
+            .with_clock(&clock);
+        let block = logger.warning("warning").block.unwrap();
+
+        assert_eq!(block.number, 123);
+        assert_eq!(block.hash, "abc");
+        assert_eq!(block.parent_hash, "parent");
+        assert_eq!(block.timestamp.unwrap().seconds, 1677000000);
+
+        let next = Block {
+            hash: "def".to_string(),
+            number: 124,
+            ..Default::default()
+        };
+        let logger = logger.with_clock(&next);
+        assert_eq!(logger.warning("warning").block.unwrap().parent_hash, "");
+    }
+
+    #[cfg(feature = "substreams")]
+    #[test]
+    fn test_with_substreams_clock() {
+        use substreams::pb::substreams::Clock;
+
+        let clock = Clock {
+            id: "abc".to_string(),
+            number: 123,
+            timestamp: Some(prost_types::Timestamp {
+                seconds: 1677000000,
+                nanos: 0,
+            }),
+        };
+        let logger = Logger::new("user-service").with_clock(&clock);
+        let block = logger.info("message").block.unwrap();
+
+        assert_eq!(block.hash, "abc");
+        assert_eq!(block.number, 123);
+        assert_eq!(block.timestamp.as_ref().unwrap().seconds, 1677000000);
+        assert_eq!(block.parent_hash, "");
+        assert_eq!(Block::from(clock), block);
+    }
+
+    #[test]
+    fn test_default_meta() {
+        let logger = Logger::new("user-service")
+            .with_default_meta(Meta::from(vec![["network", "mainnet"], ["chain", "eth"]]));
+        let operation = logger
+            .info("info message")
+            .with(Meta::from(vec![["chain", "polygon"], ["pool", "abc"]]));
+        let meta = Meta::from(&operation);
+
+        assert_eq!(meta.len(), 3);
+        assert_eq!(meta.get("network"), Some(&"mainnet".into_value()));
+        assert_eq!(meta.get("chain"), Some(&"polygon".into_value()));
+        assert_eq!(meta.get("pool"), Some(&"abc".into_value()));
+    }
+
+    #[test]
+    fn test_log() {
+        let logger = Logger::new("user-service");
+        let mut log_ops: LoggerOperations = Default::default();
+        log_ops.push(logger.log(LoggingLevels::Notice, "notice message"));
+        log_ops.push(logger.log(NpmLevels::Http, "http message"));
+
+        assert_eq!(log_ops.operations[0], logger.notice("notice message"));
+        assert_eq!(log_ops.operations[1].level, LoggingLevels::Debug as i32);
+    }
+
+    #[test]
+    fn test_with_level() {
+        let mut log_ops: LoggerOperations = Default::default();
+        let logger = Logger::new("user-service").with_level(LoggingLevels::Notice);
+        logger.push(&mut log_ops, logger.debug("debug message"));
+        logger.push(&mut log_ops, logger.info("info message"));
+        logger.push(&mut log_ops, logger.notice("notice message"));
+        logger.extend(
+            &mut log_ops,
+            vec![logger.error("error message"), logger.debug("debug message")],
+        );
+
+        assert_eq!(log_ops.operations.len(), 2);
+        assert!(logger.is_level_enabled(LoggingLevels::Emerg));
+        assert!(!logger.is_level_enabled(NpmLevels::Silly));
+
+        logger.push(&mut log_ops, crate::winston_debug!(logger, "debug {}", 1));
+        assert_eq!(log_ops.operations.len(), 2);
+        log_ops.push(logger.debug("pushed without the Logger"));
+        assert_eq!(log_ops.operations.len(), 3);
+    }
+
+    #[test]
+    fn test_with_level_npm() {
+        let logger = Logger::new("user-service")
+            .with_level_set(LevelSets::Npm)
+            .with_level(NpmLevels::Http);
+
+        let mut log_ops: LoggerOperations = Default::default();
+        logger.push(&mut log_ops, logger.http("http message"));
+        logger.push(&mut log_ops, logger.verbose("verbose message"));
+
+        assert_eq!(log_ops.operations.len(), 1);
+        assert_eq!(log_ops.operations[0].level_name, "http");
+    }
+
+    #[test]
+    fn test_level_set() {
+        let logger = Logger::new("user-service").with_level_set(LevelSets::Cli);
+        let operation = logger.http("http message");
+
+        assert_eq!(operation.level, LoggingLevels::Debug as i32);
+        assert_eq!(operation.level_set, LevelSets::Cli as i32);
+        assert_eq!(operation.level_name, "debug");
+        assert_eq!(logger.help("help message").level_name, "help");
+        assert_eq!(
+            Logger::new("user-service").warn("warn").level_name,
+            "warning"
+        );
+    }
+
+    #[test]
+    fn test_child() {
+        let logger = Logger::new("dex")
+            .with_parent_hash("parent")
+            .with_default_meta(Meta::from(vec![["network", "mainnet"], ["pool", "abc"]]));
+        let child = logger.child_service("swaps", Meta::from(vec![["pool", "def"]]));
+        let operation = child.info("info message");
+        let meta = Meta::from(&operation);
+
+        assert_eq!(operation.service, "dex.swaps");
+        assert_eq!(operation.block.unwrap().parent_hash, "parent");
+        assert_eq!(meta.get("network"), Some(&"mainnet".into_value()));
+        assert_eq!(meta.get("pool"), Some(&"def".into_value()));
+        assert_eq!(logger.child(Meta::new()), logger);
+    }
 }
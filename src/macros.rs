@@ -0,0 +1,204 @@
+/// Log formatted message with metadata at a given level
+///
+/// Trailing `key = value` pairs after `;` are inserted into the operation `Meta`.
+/// The operation is built whatever the Logger level, push it with `Logger::push`
+/// to skip it below that level.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{winston_log, Logger, LoggingLevels};
+/// let logger = Logger::new("user-service");
+/// let operation = winston_log!(logger, LoggingLevels::Info, "swap {} -> {}", "a", "b"; pool = "abc", amount = 10);
+/// assert_eq!(operation.message, "swap a -> b");
+/// ```
+#[macro_export]
+macro_rules! winston_log {
+    ($logger:expr, $level:expr, $fmt:literal $(, $arg:expr)* $(,)? ; $($key:tt = $value:expr),* $(,)?) => {{
+        let mut meta = $crate::Meta::new();
+        $(meta.insert($crate::winston_log!(@key $key), $value);)*
+        $logger.log($level, &::std::format!($fmt $(, $arg)*)).with(meta)
+    }};
+    ($logger:expr, $level:expr, $fmt:literal $(, $arg:expr)* $(,)?) => {
+        $logger.log($level, &::std::format!($fmt $(, $arg)*))
+    };
+    (@key $key:ident) => {
+        ::std::stringify!($key)
+    };
+    (@key $key:literal) => {
+        $key
+    };
+}
+
+/// Emergency: system is unusable
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{winston_emerg, Logger};
+/// let logger = Logger::new("user-service");
+/// winston_emerg!(logger, "block {} is unusable", 1; block = 1);
+/// ```
+#[macro_export]
+macro_rules! winston_emerg {
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::winston_log!($logger, $crate::LoggingLevels::Emerg, $($arg)+)
+    };
+}
+
+/// Alert: action must be taken immediately
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{winston_alert, Logger};
+/// let logger = Logger::new("user-service");
+/// winston_alert!(logger, "alert message");
+/// ```
+#[macro_export]
+macro_rules! winston_alert {
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::winston_log!($logger, $crate::LoggingLevels::Alert, $($arg)+)
+    };
+}
+
+/// Critical: critical conditions
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{winston_crit, Logger};
+/// let logger = Logger::new("user-service");
+/// winston_crit!(logger, "critical message");
+/// ```
+#[macro_export]
+macro_rules! winston_crit {
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::winston_log!($logger, $crate::LoggingLevels::Crit, $($arg)+)
+    };
+}
+
+/// Error: error conditions
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{winston_error, Logger};
+/// let logger = Logger::new("user-service");
+/// winston_error!(logger, "failed to decode {}", "event"; index = 0);
+/// ```
+#[macro_export]
+macro_rules! winston_error {
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::winston_log!($logger, $crate::LoggingLevels::Error, $($arg)+)
+    };
+}
+
+/// Warning: warning conditions
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{winston_warning, Logger};
+/// let logger = Logger::new("user-service");
+/// winston_warning!(logger, "warning message");
+/// ```
+#[macro_export]
+macro_rules! winston_warning {
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::winston_log!($logger, $crate::LoggingLevels::Warning, $($arg)+)
+    };
+}
+
+/// Notice: normal but significant condition
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{winston_notice, Logger};
+/// let logger = Logger::new("user-service");
+/// winston_notice!(logger, "notice message");
+/// ```
+#[macro_export]
+macro_rules! winston_notice {
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::winston_log!($logger, $crate::LoggingLevels::Notice, $($arg)+)
+    };
+}
+
+/// Informational: informational messages
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{winston_info, Logger, LoggerOperations};
+/// let mut log_ops: LoggerOperations = Default::default();
+/// let logger = Logger::new("user-service");
+/// let (a, b, pool) = ("EOS", "USDT", "abc");
+/// logger.push(&mut log_ops, winston_info!(logger, "swap {} -> {}", a, b; pool = pool, amount = 1.5));
+/// ```
+#[macro_export]
+macro_rules! winston_info {
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::winston_log!($logger, $crate::LoggingLevels::Info, $($arg)+)
+    };
+}
+
+/// Debug: debug-level messages
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{winston_debug, Logger};
+/// let logger = Logger::new("user-service");
+/// winston_debug!(logger, "debug message");
+/// ```
+#[macro_export]
+macro_rules! winston_debug {
+    ($logger:expr, $($arg:tt)+) => {
+        $crate::winston_log!($logger, $crate::LoggingLevels::Debug, $($arg)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntoValue, Logger, LoggingLevels, Meta};
+
+    #[test]
+    fn test_macro_meta() {
+        let logger = Logger::new("user-service");
+        let pool = "abc".to_string();
+        let operation = winston_info!(logger, "swap {} -> {}", "a", "b"; pool = &pool, amount = 10, "tx.hash" = "0x1",);
+        let meta = Meta::from(&operation);
+
+        assert_eq!(operation.message, "swap a -> b");
+        assert_eq!(operation.level, LoggingLevels::Info as i32);
+        assert_eq!(meta.get("pool"), Some(&"abc".into_value()));
+        assert_eq!(meta.get("amount"), Some(&10.into_value()));
+        assert_eq!(meta.get("tx.hash"), Some(&"0x1".into_value()));
+    }
+
+    #[test]
+    fn test_macro_levels() {
+        let logger = Logger::new("user-service");
+        let value = 42;
+
+        assert_eq!(
+            winston_emerg!(logger, "message").level,
+            LoggingLevels::Emerg as i32
+        );
+        assert_eq!(
+            winston_alert!(logger, "message").level,
+            LoggingLevels::Alert as i32
+        );
+        assert_eq!(
+            winston_crit!(logger, "message").level,
+            LoggingLevels::Crit as i32
+        );
+        assert_eq!(
+            winston_error!(logger, "message").level,
+            LoggingLevels::Error as i32
+        );
+        assert_eq!(
+            winston_warning!(logger, "message").level,
+            LoggingLevels::Warning as i32
+        );
+        assert_eq!(
+            winston_notice!(logger, "message").level,
+            LoggingLevels::Notice as i32
+        );
+        assert_eq!(winston_debug!(logger, "value {value}").message, "value 42");
+        assert!(winston_info!(logger, "message").meta.is_none());
+    }
+}
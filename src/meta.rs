@@ -1,63 +1,346 @@
-use crate::LoggerOperation;
-use std::{collections::HashMap, convert::From};
+use crate::{validate_key, value_to_string, IntoValue, KeyError, KeyPolicy, LoggerOperation};
+use prost_types::{value::Kind, Struct, Value};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    convert::From,
+    fmt,
+};
 
-#[derive(Eq, Debug, Clone, PartialEq, Default)]
-pub struct Meta(HashMap<String, String>);
+/// Resolution of key conflicts when merging `Meta`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    /// Incoming entries replace existing ones
+    #[default]
+    Overwrite,
+    /// Existing entries are kept, incoming ones are dropped
+    KeepExisting,
+    /// Conflicting keys fail the merge, leaving the meta unchanged
+    Error,
+}
+
+/// Error returned by `MergeStrategy::Error` merges, holding the conflicting key
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergeError(pub String);
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "meta key already set: {}", self.0)
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// Metadata entries, preserving insertion order
+///
+/// Encoded as a `google.protobuf.Struct`, whose keys are sorted for a deterministic wire encoding.
+/// Insertion order is therefore lost once attached with `LoggerOperation::with`:
+/// `Meta::from(&operation)` returns the entries sorted by key.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Meta {
+    fields: Vec<(String, Value)>,
+    private: BTreeSet<String>,
+    policy: KeyPolicy,
+    key_errors: Vec<KeyError>,
+}
 
 impl Meta {
+    /// Key of the list holding values appended with `Meta::push`
+    pub const ITEMS_KEY: &'static str = "items";
+
     pub fn new() -> Self {
-        Meta(HashMap::default())
+        Meta::default()
+    }
+    /// Validate keys inserted from now on, and the existing ones, with `policy`
+    #[must_use]
+    pub fn with_key_policy(self, policy: KeyPolicy) -> Self {
+        let mut meta = Meta {
+            policy,
+            key_errors: self.key_errors,
+            ..Default::default()
+        };
+        for (k, v) in self.fields {
+            match self.private.contains(&k) {
+                true => meta.insert_private(&k, v),
+                false => meta.insert(&k, v),
+            }
+        }
+        meta
+    }
+    /// Keys rejected, renamed or reported by the `KeyPolicy`
+    pub fn key_errors(&self) -> &[KeyError] {
+        &self.key_errors
+    }
+    /// Insert entry, an existing key keeps its position
+    ///
+    /// The key is validated with the `KeyPolicy`, see `Meta::with_key_policy`.
+    pub fn insert<V: IntoValue>(&mut self, k: &str, v: V) {
+        self.set_entry(k, v.into_value(), false);
+    }
+    /// Insert private entry, stripped by `LoggerOperations::ignore_private`
+    pub fn insert_private<V: IntoValue>(&mut self, k: &str, v: V) {
+        self.set_entry(k, v.into_value(), true);
+    }
+    /// Insert entry, failing on reserved or invalid keys whatever the `KeyAction`
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::Meta;
+    /// let mut meta = Meta::new();
+    /// assert!(meta.try_insert("message", "value").is_err());
+    /// assert!(meta.try_insert("pool", "value").is_ok());
+    /// ```
+    pub fn try_insert<V: IntoValue>(&mut self, k: &str, v: V) -> Result<(), KeyError> {
+        let k = match self.policy.normalize {
+            true => crate::normalize_key(k),
+            false => k.to_string(),
+        };
+        validate_key(&k)?;
+        self.private.remove(&k);
+        self.set(&k, v.into_value());
+        Ok(())
+    }
+    /// Append value to the list under `Meta::ITEMS_KEY`
+    pub fn push<V: IntoValue>(&mut self, value: V) {
+        self.push_to(Meta::ITEMS_KEY, value);
+    }
+    /// Append value to the list under `k`, an existing non-list value becomes the first item
+    pub fn push_to<V: IntoValue>(&mut self, k: &str, value: V) {
+        let k = match self.resolve_key(k) {
+            Some(k) => k,
+            None => return,
+        };
+        let value = value.into_value();
+        match self.position(&k) {
+            Some(index) => {
+                let existing = &mut self.fields[index].1;
+                match &mut existing.kind {
+                    Some(Kind::ListValue(list)) => list.values.push(value),
+                    _ => *existing = vec![existing.to_owned(), value].into_value(),
+                }
+            }
+            None => self.fields.push((k, vec![value].into_value())),
+        }
+    }
+    pub fn get(&self, k: &str) -> Option<&Value> {
+        self.position(k).map(|index| &self.fields[index].1)
+    }
+    pub fn remove(&mut self, k: &str) -> Option<Value> {
+        self.private.remove(k);
+        self.position(k).map(|index| self.fields.remove(index).1)
+    }
+    /// Keys in insertion order
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.fields.iter().map(|(k, _)| k.as_str())
+    }
+    /// Entries in insertion order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.fields.iter().map(|(k, v)| (k.as_str(), v))
+    }
+    pub fn is_private(&self, k: &str) -> bool {
+        self.private.contains(k)
+    }
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+    /// Merge another `Meta`, its entries take precedence on key conflicts
+    ///
+    /// Keys are validated with the `KeyPolicy` of this `Meta`, see `Meta::with_key_policy`.
+    pub fn extend(&mut self, other: Meta) {
+        self.key_errors.extend(other.key_errors);
+        for (k, v) in other.fields {
+            let private = other.private.contains(&k);
+            if let Some(k) = self.resolve_other_key(&k, &other.policy) {
+                match private {
+                    true => self.private.insert(k.to_owned()),
+                    false => self.private.remove(&k),
+                };
+                self.set(&k, v);
+            }
+        }
+    }
+    /// Merge another `Meta`, resolving key conflicts with `strategy`
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{MergeStrategy, Meta};
+    /// let mut meta = Meta::from(vec![("key", 1)]);
+    ///
+    /// meta.merge(Meta::from(vec![("key", 2)]), MergeStrategy::KeepExisting).unwrap();
+    /// assert!(meta.merge(Meta::from(vec![("key", 3)]), MergeStrategy::Error).is_err());
+    /// ```
+    pub fn merge(&mut self, other: Meta, strategy: MergeStrategy) -> Result<(), MergeError> {
+        match strategy {
+            MergeStrategy::Overwrite => self.extend(other),
+            MergeStrategy::KeepExisting => {
+                self.key_errors.extend(other.key_errors);
+                for (k, v) in other.fields {
+                    let private = other.private.contains(&k);
+                    if let Some(k) = self.resolve_other_key(&k, &other.policy) {
+                        if self.position(&k).is_none() {
+                            if private {
+                                self.private.insert(k.to_owned());
+                            }
+                            self.fields.push((k, v));
+                        }
+                    }
+                }
+            }
+            MergeStrategy::Error => {
+                let conflict = other
+                    .keys()
+                    .filter_map(|k| self.policy.resolve(k).0)
+                    .find(|k| self.position(k).is_some());
+                if let Some(k) = conflict {
+                    return Err(MergeError(k));
+                }
+                self.extend(other);
+            }
+        }
+        Ok(())
     }
-    pub fn insert(&mut self, k: &str, v: &str) {
-        self.0.insert(k.to_string(), v.to_string());
+    /// Key to write under the `KeyPolicy`, renamed keys landing on an existing key are collisions
+    fn resolve_key(&mut self, k: &str) -> Option<String> {
+        let (key, error) = self.policy.resolve(k);
+        self.key_errors.extend(error);
+        if let Some(key) = &key {
+            if key != k && self.position(key).is_some() {
+                self.key_errors.push(KeyError::Collision(key.to_owned()));
+            }
+        }
+        key
     }
-    pub fn push(&mut self, value: &str) {
-        self.0.insert(self.0.len().to_string(), value.to_string());
+    /// Key of an entry of another `Meta`, already resolved when both share the same `KeyPolicy`
+    fn resolve_other_key(&mut self, k: &str, policy: &KeyPolicy) -> Option<String> {
+        match *policy == self.policy {
+            true => Some(k.to_string()),
+            false => self.resolve_key(k),
+        }
+    }
+    fn set_entry(&mut self, k: &str, v: Value, private: bool) {
+        if let Some(k) = self.resolve_key(k) {
+            match private {
+                true => self.private.insert(k.to_owned()),
+                false => self.private.remove(&k),
+            };
+            self.set(&k, v);
+        }
+    }
+    fn position(&self, k: &str) -> Option<usize> {
+        self.fields.iter().position(|(key, _)| key == k)
+    }
+    fn set(&mut self, k: &str, v: Value) {
+        match self.position(k) {
+            Some(index) => self.fields[index].1 = v,
+            None => self.fields.push((k.to_string(), v)),
+        }
     }
 }
 
+/// Items are held as a list under `Meta::ITEMS_KEY`
 impl From<Vec<&str>> for Meta {
     #[inline]
-    #[must_use]
     fn from(items: Vec<&str>) -> Self {
-        let mut object: HashMap<String, String> = Default::default();
-        for (key, value) in items.into_iter().enumerate() {
-            object.insert(key.to_string(), value.to_string());
-        }
-        Meta(object)
+        let mut meta = Meta::new();
+        meta.insert(Meta::ITEMS_KEY, items);
+        meta
     }
 }
 
 impl From<Vec<[&str; 2]>> for Meta {
     #[inline]
-    #[must_use]
     fn from(items: Vec<[&str; 2]>) -> Self {
-        let mut object: HashMap<String, String> = Default::default();
-        for item in items {
-            if item.len() != 2 {
-                continue;
-            }
-            let k = item[0];
-            let v = item[1];
-            object.insert(k.to_string(), v.to_string());
+        let mut meta = Meta::new();
+        for [k, v] in items {
+            meta.insert(k, v);
+        }
+        meta
+    }
+}
+
+impl<V: IntoValue> From<Vec<(&str, V)>> for Meta {
+    #[inline]
+    fn from(items: Vec<(&str, V)>) -> Self {
+        let mut meta = Meta::new();
+        for (k, v) in items {
+            meta.insert(k, v);
         }
-        Meta(object)
+        meta
     }
 }
 
+impl From<Meta> for Struct {
+    #[inline]
+    fn from(meta: Meta) -> Struct {
+        Struct {
+            fields: meta.fields.into_iter().collect(),
+        }
+    }
+}
+
+impl From<Struct> for Meta {
+    #[inline]
+    fn from(object: Struct) -> Self {
+        let mut meta = Meta::new();
+        for (k, v) in object.fields {
+            meta.insert(&k, v);
+        }
+        meta
+    }
+}
+
+/// Non-string values are rendered as JSON
 impl From<Meta> for HashMap<String, String> {
     #[inline]
-    #[must_use]
     fn from(meta: Meta) -> HashMap<String, String> {
-        meta.0
+        meta.fields
+            .iter()
+            .map(|(k, v)| (k.to_owned(), value_to_string(v)))
+            .collect()
     }
 }
 
+/// Keys are sorted, `HashMap` iteration order being random
 impl From<HashMap<String, String>> for Meta {
     #[inline]
-    #[must_use]
     fn from(object: HashMap<String, String>) -> Self {
-        Meta(object)
+        let mut entries: Vec<(String, String)> = object.into_iter().collect();
+        entries.sort();
+        let mut meta = Meta::new();
+        for (k, v) in entries {
+            meta.insert(&k, v);
+        }
+        meta
+    }
+}
+
+impl From<BTreeMap<String, String>> for Meta {
+    #[inline]
+    fn from(object: BTreeMap<String, String>) -> Self {
+        let mut meta = Meta::new();
+        for (k, v) in object {
+            meta.insert(&k, v);
+        }
+        meta
+    }
+}
+
+/// Decode the metadata of an operation, including string-only `legacy_meta` payloads
+///
+/// Typed `meta` entries take precedence over `legacy_meta` entries sharing the same key.
+impl From<&LoggerOperation> for Meta {
+    #[inline]
+    fn from(operation: &LoggerOperation) -> Self {
+        let mut meta = Meta::from(operation.legacy_meta.to_owned());
+        if let Some(object) = &operation.meta {
+            for (k, v) in &object.fields {
+                meta.insert(k, v.to_owned());
+            }
+        }
+        meta.private.extend(operation.private_keys.iter().cloned());
+        meta
     }
 }
 
@@ -65,6 +348,9 @@ impl LoggerOperation {
     /// Set label to Counter
     /// Labels represents a collection of label name -> value mappings.
     ///
+    /// Merged over the Logger default metadata, taking precedence on key conflicts.
+    /// Keys are validated with the `KeyPolicy` of `meta`, see `Meta::with_key_policy`.
+    ///
     /// ### Example
     /// ```
     /// use substreams_sink_winston::{Logger, Meta};
@@ -74,18 +360,105 @@ impl LoggerOperation {
     /// ```
     #[inline]
     pub fn with(self, meta: Meta) -> Self {
+        let mut merged = Meta::from(self.meta.unwrap_or_default());
+        merged.private.extend(self.private_keys);
+        merged.policy = meta.policy;
+        merged.extend(meta);
         LoggerOperation {
-            level: self.level,
-            message: self.message,
-            meta: meta.into(),
-            service: self.service,
+            private_keys: merged.private.iter().cloned().collect(),
+            meta: Some(merged.into()),
+            ..self
+        }
+    }
+
+    /// Merge metadata, resolving key conflicts with `strategy`
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, MergeStrategy, Meta};
+    /// let logger = Logger::new("user-service");
+    /// let operation = logger
+    ///     .info("message")
+    ///     .with(Meta::from(vec![("pool", "a")]))
+    ///     .with_merged(Meta::from(vec![("pool", "b")]), MergeStrategy::Error);
+    /// assert!(operation.is_err());
+    /// ```
+    pub fn with_merged(self, meta: Meta, strategy: MergeStrategy) -> Result<Self, MergeError> {
+        let mut merged = Meta::from(self.meta.unwrap_or_default());
+        merged.private.extend(self.private_keys);
+        merged.policy = meta.policy;
+        merged.merge(meta, strategy)?;
+        Ok(LoggerOperation {
+            private_keys: merged.private.iter().cloned().collect(),
+            meta: Some(merged.into()),
+            ..self
+        })
+    }
+
+    /// Set a single metadata entry, replacing an existing value
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::Logger;
+    /// let logger = Logger::new("user-service");
+    /// logger.info("message").with_field("block", 123).with_field("pool", "a");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_field<V: IntoValue>(self, k: &str, v: V) -> Self {
+        let mut meta = Meta::new();
+        meta.insert(k, v);
+        self.with(meta)
+    }
+
+    /// Merge metadata, failing when its `KeyPolicy` rejected, renamed or reported any key
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{KeyAction, KeyPolicy, Logger, Meta};
+    /// let logger = Logger::new("user-service");
+    /// let mut meta = Meta::new().with_key_policy(KeyPolicy::new(KeyAction::Reject));
+    /// meta.insert("level", "high");
+    /// assert!(logger.info("message").try_with(meta).is_err());
+    /// ```
+    pub fn try_with(self, meta: Meta) -> Result<Self, KeyError> {
+        if let Some(error) = meta.key_errors.first() {
+            return Err(error.to_owned());
         }
+        Ok(self.with(meta))
+    }
+
+    /// Validate the keys of `meta` with `policy`, returning the keys rejected, renamed or reported
+    ///
+    /// `legacy_meta` keys are left as-is.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{KeyAction, KeyPolicy, Logger};
+    /// let logger = Logger::new("user-service");
+    /// let mut operation = logger.info("message").with_field("level", "high");
+    /// let errors = operation.apply_key_policy(KeyPolicy::new(KeyAction::Reject));
+    /// assert_eq!(errors.len(), 1);
+    /// ```
+    pub fn apply_key_policy(&mut self, policy: KeyPolicy) -> Vec<KeyError> {
+        let object = match self.meta.take() {
+            Some(object) => object,
+            None => return vec![],
+        };
+        let mut meta = Meta::from(object);
+        meta.private.extend(self.private_keys.drain(..));
+        let meta = meta.with_key_policy(policy);
+        self.private_keys = meta.private.iter().cloned().collect();
+        let errors = meta.key_errors.to_owned();
+        self.meta = Some(meta.into());
+        errors
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Logger, Meta};
+    use crate::{IntoValue, Logger, MergeError, MergeStrategy, Meta};
+    use prost::Message;
     use std::collections::HashMap;
 
     #[test]
@@ -131,4 +504,179 @@ mod tests {
 
         logger.info("info message").with(meta);
     }
+
+    #[test]
+    fn test_meta_push_list() {
+        let mut meta = Meta::new();
+        meta.insert("0", "kept");
+        meta.push("a");
+        meta.push(1);
+        meta.push_to("tags", "x");
+        meta.insert("single", "first");
+        meta.push_to("single", "second");
+
+        assert_eq!(meta.len(), 4);
+        assert_eq!(meta.get("0"), Some(&"kept".into_value()));
+        assert_eq!(
+            meta.get(Meta::ITEMS_KEY),
+            Some(&vec!["a".into_value(), 1.into_value()].into_value())
+        );
+        assert_eq!(meta.get("tags"), Some(&vec!["x"].into_value()));
+        assert_eq!(
+            meta.get("single"),
+            Some(&vec!["first", "second"].into_value())
+        );
+    }
+
+    #[test]
+    fn test_meta_array_round_trip() {
+        let logger = Logger::new("user-service");
+        let operation = logger.info("info message").with(Meta::from(vec!["a", "b"]));
+        let meta = Meta::from(&operation);
+
+        assert_eq!(meta.len(), 1);
+        assert_eq!(
+            meta.get(Meta::ITEMS_KEY),
+            Some(&vec!["a", "b"].into_value())
+        );
+    }
+
+    #[test]
+    fn test_meta_typed() {
+        let logger = Logger::new("user-service");
+        let mut nested = Meta::new();
+        nested.insert("enabled", true);
+
+        let mut meta = Meta::new();
+        meta.insert("block", 123);
+        meta.insert("price", 1.5);
+        meta.insert("tags", vec!["a", "b"]);
+        meta.insert("missing", None::<u64>);
+        meta.insert("nested", nested.clone());
+
+        let operation = logger.info("info message").with(meta);
+        let meta = Meta::from(&operation);
+        assert_eq!(meta.get("block"), Some(&123.into_value()));
+        assert_eq!(meta.get("nested"), Some(&nested.into_value()));
+    }
+
+    #[test]
+    fn test_meta_insertion_order() {
+        let mut meta = Meta::new();
+        meta.insert("b", 1);
+        meta.insert("a", 2);
+        meta.insert("c", 3);
+        meta.insert("b", 4);
+        meta.remove("c");
+
+        assert_eq!(meta.keys().collect::<Vec<_>>(), vec!["b", "a"]);
+        assert_eq!(meta.get("b"), Some(&4.into_value()));
+    }
+
+    #[test]
+    fn test_meta_deterministic_encoding() {
+        let logger = Logger::new("user-service");
+        let a = logger
+            .info("info message")
+            .with(Meta::from(vec![("x", 1), ("y", 2), ("z", 3)]));
+        let b = logger
+            .info("info message")
+            .with(Meta::from(vec![("z", 3), ("x", 1), ("y", 2)]));
+
+        assert_eq!(a.encode_to_vec(), b.encode_to_vec());
+    }
+
+    #[test]
+    fn test_meta_legacy_deterministic_encoding() {
+        let logger = Logger::new("user-service");
+        let mut a = logger.info("info message");
+        let mut b = logger.info("info message");
+        for k in ["x", "y", "z"] {
+            a.legacy_meta.insert(k.to_string(), k.to_string());
+        }
+        for k in ["z", "x", "y"] {
+            b.legacy_meta.insert(k.to_string(), k.to_string());
+        }
+
+        assert_eq!(a.encode_to_vec(), b.encode_to_vec());
+    }
+
+    #[test]
+    fn test_meta_legacy() {
+        let mut operation = Logger::new("user-service").info("info message");
+        operation
+            .legacy_meta
+            .insert("key".to_string(), "legacy".to_string());
+        operation
+            .legacy_meta
+            .insert("old".to_string(), "value".to_string());
+        operation = operation.with(Meta::from(vec![("key", 1)]));
+
+        let meta = Meta::from(&operation);
+        assert_eq!(meta.get("key"), Some(&1.into_value()));
+        assert_eq!(meta.get("old"), Some(&"value".into_value()));
+    }
+
+    #[test]
+    fn test_meta_with_layers() {
+        let logger = Logger::new("user-service");
+        let operation = logger
+            .info("info message")
+            .with(Meta::from(vec![("a", 1)]))
+            .with(Meta::from(vec![("b", 2)]))
+            .with_field("a", 3);
+
+        let meta = Meta::from(&operation);
+        assert_eq!(meta.get("a"), Some(&3.into_value()));
+        assert_eq!(meta.get("b"), Some(&2.into_value()));
+    }
+
+    #[test]
+    fn test_meta_merge_strategies() {
+        let mut existing = Meta::from(vec![("a", 1)]);
+        existing.insert_private("secret", "x");
+        let mut incoming = Meta::from(vec![("a", 2), ("c", 3)]);
+        incoming.insert_private("d", 4);
+
+        let mut meta = existing.clone();
+        meta.merge(incoming.clone(), MergeStrategy::Overwrite)
+            .unwrap();
+        assert_eq!(meta.get("a"), Some(&2.into_value()));
+
+        let mut meta = existing.clone();
+        meta.merge(incoming.clone(), MergeStrategy::KeepExisting)
+            .unwrap();
+        assert_eq!(meta.get("a"), Some(&1.into_value()));
+        assert_eq!(meta.get("c"), Some(&3.into_value()));
+        assert!(meta.is_private("secret"));
+        assert!(meta.is_private("d"));
+
+        let mut meta = existing.clone();
+        assert_eq!(
+            meta.merge(incoming, MergeStrategy::Error),
+            Err(MergeError("a".to_string()))
+        );
+        assert_eq!(meta, existing);
+    }
+
+    #[test]
+    fn test_with_merged() {
+        let logger =
+            Logger::new("user-service").with_default_meta(Meta::from(vec![("env", "prod")]));
+        let operation = logger
+            .info("info message")
+            .with_merged(
+                Meta::from(vec![("env", "dev"), ("b", "1")]),
+                MergeStrategy::KeepExisting,
+            )
+            .unwrap();
+
+        let meta = Meta::from(&operation);
+        assert_eq!(meta.get("env"), Some(&"prod".into_value()));
+        assert_eq!(meta.get("b"), Some(&"1".into_value()));
+        assert!(logger
+            .info("info message")
+            .with_merged(Meta::from(vec![("env", "dev")]), MergeStrategy::Error)
+            .is_err());
+    }
 }
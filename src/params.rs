@@ -0,0 +1,231 @@
+use crate::{Level, Logger, ParseLevelError};
+use std::{fmt, str::FromStr};
+
+/// Error returned when Substreams module params cannot configure a Logger
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParamsError {
+    /// Param is not a `key=value` pair
+    Malformed(String),
+    /// Param key is not supported
+    UnknownKey(String),
+    /// Param key is set more than once
+    DuplicateKey(String),
+    /// `level_set` is not one of `syslog`, `npm` or `cli`
+    InvalidLevelSet(String),
+    /// `level` is not a known winston level
+    InvalidLevel(ParseLevelError),
+    /// `service` is required but missing or empty
+    MissingService,
+}
+
+impl fmt::Display for ParamsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParamsError::Malformed(param) => {
+                write!(f, "malformed param, expected key=value: {}", param)
+            }
+            ParamsError::UnknownKey(key) => write!(f, "unknown param key: {}", key),
+            ParamsError::DuplicateKey(key) => write!(f, "duplicate param key: {}", key),
+            ParamsError::InvalidLevelSet(value) => write!(f, "invalid level set: {}", value),
+            ParamsError::InvalidLevel(err) => err.fmt(f),
+            ParamsError::MissingService => write!(f, "missing service param"),
+        }
+    }
+}
+
+impl std::error::Error for ParamsError {}
+
+impl From<ParseLevelError> for ParamsError {
+    fn from(err: ParseLevelError) -> Self {
+        ParamsError::InvalidLevel(err)
+    }
+}
+
+impl Logger {
+    /// Configure Logger from Substreams module params
+    ///
+    /// Params are `&` separated `key=value` pairs, percent-decoded (`+` decodes to a space):
+    ///
+    /// - `service`: Logger service
+    /// - `level`: minimum level (ex: `warning`)
+    /// - `level_set`: `syslog`, `npm` or `cli`
+    /// - `meta.<key>`: default metadata entry
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggingLevels};
+    /// let logger = Logger::new("user-service")
+    ///     .with_params("level=warning&service=dex&meta.network=mainnet")
+    ///     .unwrap();
+    /// assert_eq!(logger.service, "dex");
+    /// assert!(!logger.is_level_enabled(LoggingLevels::Info));
+    /// ```
+    pub fn with_params(self, params: &str) -> Result<Self, ParamsError> {
+        let mut logger = self;
+        let mut level = None;
+        let mut seen: Vec<String> = vec![];
+
+        for param in params.split('&').map(str::trim).filter(|p| !p.is_empty()) {
+            let malformed = || ParamsError::Malformed(param.to_string());
+            let (key, value) = match param.split_once('=') {
+                Some((key, value)) if !key.trim().is_empty() => (
+                    percent_decode(key.trim()).ok_or_else(malformed)?,
+                    percent_decode(value.trim()).ok_or_else(malformed)?,
+                ),
+                _ => return Err(malformed()),
+            };
+            if seen.contains(&key) {
+                return Err(ParamsError::DuplicateKey(key));
+            }
+
+            match key.as_str() {
+                "service" => logger.service = value,
+                "level" => level = Some(value),
+                "level_set" => {
+                    logger.level_set = value
+                        .parse()
+                        .map_err(|_| ParamsError::InvalidLevelSet(value))?
+                }
+                _ => match key.strip_prefix("meta.") {
+                    Some(meta_key) if !meta_key.is_empty() => {
+                        logger.default_meta.insert(meta_key, value)
+                    }
+                    _ => return Err(ParamsError::UnknownKey(key)),
+                },
+            }
+            seen.push(key);
+        }
+        if let Some(level) = level {
+            logger.level = Some(Level::from_name(logger.level_set, &level)?);
+        }
+        if logger.service.is_empty() {
+            return Err(ParamsError::MissingService);
+        }
+        Ok(logger)
+    }
+}
+
+/// Decode `%XX` escapes and `+` as a space, `None` on invalid escapes or UTF-8
+fn percent_decode(s: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut input = s.bytes();
+    while let Some(byte) = input.next() {
+        match byte {
+            b'%' => {
+                let hex = [input.next()?, input.next()?];
+                if !hex.iter().all(u8::is_ascii_hexdigit) {
+                    return None;
+                }
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+            }
+            b'+' => bytes.push(b' '),
+            byte => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// Create Logger from Substreams module params, `service` is required
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::Logger;
+/// let logger: Logger = "service=dex&level=info".parse().unwrap();
+/// ```
+impl FromStr for Logger {
+    type Err = ParamsError;
+
+    fn from_str(params: &str) -> Result<Self, Self::Err> {
+        Logger::default().with_params(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        IntoValue, Level, LevelSets, Logger, LoggingLevels, NpmLevels, ParamsError, ParseLevelError,
+    };
+
+    #[test]
+    fn test_with_params() {
+        let logger = Logger::new("user-service")
+            .with_params("level=warning&service=dex&meta.network=mainnet&meta.chain=eth")
+            .unwrap();
+
+        assert_eq!(logger.service, "dex");
+        assert_eq!(logger.level, Some(Level::Syslog(LoggingLevels::Warning)));
+        assert_eq!(logger.default_meta.len(), 2);
+        assert_eq!(
+            logger.default_meta.get("network"),
+            Some(&"mainnet".into_value())
+        );
+    }
+
+    #[test]
+    fn test_params_percent_decoding() {
+        let logger = Logger::new("user-service")
+            .with_params("meta.note=hello%20world&meta.tag=a+b&meta.pct=100%25&meta.caf%C3%A9=1")
+            .unwrap();
+
+        assert_eq!(
+            logger.default_meta.get("note"),
+            Some(&"hello world".into_value())
+        );
+        assert_eq!(logger.default_meta.get("tag"), Some(&"a b".into_value()));
+        assert_eq!(logger.default_meta.get("pct"), Some(&"100%".into_value()));
+        assert!(logger.default_meta.get("café").is_some());
+        assert_eq!(
+            Logger::new("user-service").with_params("meta.bad=%zz"),
+            Err(ParamsError::Malformed("meta.bad=%zz".to_string()))
+        );
+        assert_eq!(
+            Logger::new("user-service").with_params("meta.bad=%FF"),
+            Err(ParamsError::Malformed("meta.bad=%FF".to_string()))
+        );
+        assert_eq!(
+            Logger::new("user-service").with_params("meta.bad=%+f"),
+            Err(ParamsError::Malformed("meta.bad=%+f".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_from_params() {
+        let logger: Logger = "level=http&level_set=npm&service=dex".parse().unwrap();
+
+        assert_eq!(logger.level_set, LevelSets::Npm);
+        assert_eq!(logger.level, Some(Level::Npm(NpmLevels::Http)));
+        assert_eq!("".parse::<Logger>(), Err(ParamsError::MissingService));
+    }
+
+    #[test]
+    fn test_params_errors() {
+        let logger = Logger::new("user-service");
+
+        assert_eq!(
+            logger.clone().with_params("level"),
+            Err(ParamsError::Malformed("level".to_string()))
+        );
+        assert_eq!(
+            logger.clone().with_params("colors=true"),
+            Err(ParamsError::UnknownKey("colors".to_string()))
+        );
+        assert_eq!(
+            logger.clone().with_params("meta.=value"),
+            Err(ParamsError::UnknownKey("meta.".to_string()))
+        );
+        assert_eq!(
+            logger.clone().with_params("level=info&level=debug"),
+            Err(ParamsError::DuplicateKey("level".to_string()))
+        );
+        assert_eq!(
+            logger.clone().with_params("level_set=rfc"),
+            Err(ParamsError::InvalidLevelSet("rfc".to_string()))
+        );
+        assert_eq!(
+            logger.with_params("level=loud"),
+            Err(ParamsError::InvalidLevel(ParseLevelError(
+                "loud".to_string()
+            )))
+        );
+    }
+}
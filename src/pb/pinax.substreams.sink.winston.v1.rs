@@ -1,10 +1,12 @@
 // @generated
 /// Vector of Winston Logging messages
+#[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LoggerOperations {
     #[prost(message, repeated, tag = "1")]
     pub operations: ::prost::alloc::vec::Vec<LoggerOperation>,
 }
+#[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct LoggerOperation {
     #[prost(string, tag = "1")]
@@ -13,9 +15,68 @@ pub struct LoggerOperation {
     pub level: i32,
     #[prost(string, tag = "3")]
     pub message: ::prost::alloc::string::String,
-    #[prost(map = "string, string", tag = "4")]
-    pub meta:
-        ::std::collections::HashMap<::prost::alloc::string::String, ::prost::alloc::string::String>,
+    /// Deprecated: string-only metadata, superseded by `meta`
+    #[prost(btree_map = "string, string", tag = "4")]
+    pub legacy_meta: ::prost::alloc::collections::BTreeMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
+    #[prost(message, optional, tag = "5")]
+    pub block: ::core::option::Option<Block>,
+    #[prost(message, optional, tag = "6")]
+    pub meta: ::core::option::Option<::prost_types::Struct>,
+    /// Private operations are stripped by `ignorePrivate`
+    #[prost(bool, tag = "7")]
+    pub private: bool,
+    /// Private `meta` keys are stripped by `ignorePrivate`
+    #[prost(string, repeated, tag = "8")]
+    pub private_keys: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// Level set of the Logger which emitted the message
+    #[prost(enumeration = "LevelSets", tag = "9")]
+    pub level_set: i32,
+    /// Winston level name within `level_set` (ex: "warn")
+    #[prost(string, tag = "10")]
+    pub level_name: ::prost::alloc::string::String,
+    /// Provenance of the Winston Logging message, hex encoded with `0x` prefix
+    #[prost(string, optional, tag = "12")]
+    pub transaction_hash: ::core::option::Option<::prost::alloc::string::String>,
+    /// Ethereum log index or Antelope action ordinal
+    #[prost(uint32, optional, tag = "13")]
+    pub event_index: ::core::option::Option<u32>,
+    /// Ethereum contract address or Antelope account
+    #[prost(string, optional, tag = "14")]
+    pub contract: ::core::option::Option<::prost::alloc::string::String>,
+    /// OpenTelemetry trace context, lowercase hex encoded as in W3C `traceparent`
+    ///
+    /// 16 bytes
+    #[prost(string, optional, tag = "15")]
+    pub trace_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// 8 bytes
+    #[prost(string, optional, tag = "16")]
+    pub span_id: ::core::option::Option<::prost::alloc::string::String>,
+    /// Stable hash of service, level, message & meta, used to dedup
+    #[prost(string, optional, tag = "17")]
+    pub fingerprint: ::core::option::Option<::prost::alloc::string::String>,
+    /// Emission order within the block, assigned by `LoggerOperations` push
+    #[prost(uint64, tag = "18")]
+    pub ordinal: u64,
+    /// Constant message template (ex: "Swap of {amount} on {pool}"), arguments are held in `meta`
+    #[prost(string, optional, tag = "19")]
+    pub message_template: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Block context in which the Winston Logging message was emitted.
+/// Fields 1 to 3 are wire-compatible with `sf.substreams.v1.Clock`.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Block {
+    #[prost(string, tag = "1")]
+    pub hash: ::prost::alloc::string::String,
+    #[prost(uint64, tag = "2")]
+    pub number: u64,
+    #[prost(message, optional, tag = "3")]
+    pub timestamp: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "4")]
+    pub parent_hash: ::prost::alloc::string::String,
 }
 /// Each level is given a specific integer priority.
 /// The higher the priority the more important the message is considered to be,
@@ -43,134 +104,581 @@ pub enum LoggingLevels {
     /// Debug: debug-level messages
     Debug = 7,
 }
+impl LoggingLevels {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LoggingLevels::Emerg => "EMERG",
+            LoggingLevels::Alert => "ALERT",
+            LoggingLevels::Crit => "CRIT",
+            LoggingLevels::Error => "ERROR",
+            LoggingLevels::Warning => "WARNING",
+            LoggingLevels::Notice => "NOTICE",
+            LoggingLevels::Info => "INFO",
+            LoggingLevels::Debug => "DEBUG",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "EMERG" => Some(Self::Emerg),
+            "ALERT" => Some(Self::Alert),
+            "CRIT" => Some(Self::Crit),
+            "ERROR" => Some(Self::Error),
+            "WARNING" => Some(Self::Warning),
+            "NOTICE" => Some(Self::Notice),
+            "INFO" => Some(Self::Info),
+            "DEBUG" => Some(Self::Debug),
+            _ => None,
+        }
+    }
+}
+/// Winston level sets, `level` always holds the equivalent syslog level.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum LevelSets {
+    /// RFC5424 syslog levels (`LoggingLevels`)
+    Syslog = 0,
+    /// npm levels, winston default (`NpmLevels`)
+    Npm = 1,
+    /// cli levels (`CliLevels`)
+    Cli = 2,
+}
+impl LevelSets {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            LevelSets::Syslog => "SYSLOG",
+            LevelSets::Npm => "NPM",
+            LevelSets::Cli => "CLI",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SYSLOG" => Some(Self::Syslog),
+            "NPM" => Some(Self::Npm),
+            "CLI" => Some(Self::Cli),
+            _ => None,
+        }
+    }
+}
+/// npm levels are prioritized from 0 to 6 (highest to lowest).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum NpmLevels {
+    Error = 0,
+    Warn = 1,
+    Info = 2,
+    Http = 3,
+    Verbose = 4,
+    Debug = 5,
+    Silly = 6,
+}
+impl NpmLevels {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            NpmLevels::Error => "NPM_LEVELS_ERROR",
+            NpmLevels::Warn => "NPM_LEVELS_WARN",
+            NpmLevels::Info => "NPM_LEVELS_INFO",
+            NpmLevels::Http => "NPM_LEVELS_HTTP",
+            NpmLevels::Verbose => "NPM_LEVELS_VERBOSE",
+            NpmLevels::Debug => "NPM_LEVELS_DEBUG",
+            NpmLevels::Silly => "NPM_LEVELS_SILLY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NPM_LEVELS_ERROR" => Some(Self::Error),
+            "NPM_LEVELS_WARN" => Some(Self::Warn),
+            "NPM_LEVELS_INFO" => Some(Self::Info),
+            "NPM_LEVELS_HTTP" => Some(Self::Http),
+            "NPM_LEVELS_VERBOSE" => Some(Self::Verbose),
+            "NPM_LEVELS_DEBUG" => Some(Self::Debug),
+            "NPM_LEVELS_SILLY" => Some(Self::Silly),
+            _ => None,
+        }
+    }
+}
+/// cli levels are prioritized from 0 to 9 (highest to lowest).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CliLevels {
+    Error = 0,
+    Warn = 1,
+    Help = 2,
+    Data = 3,
+    Info = 4,
+    Debug = 5,
+    Prompt = 6,
+    Verbose = 7,
+    Input = 8,
+    Silly = 9,
+}
+impl CliLevels {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            CliLevels::Error => "CLI_LEVELS_ERROR",
+            CliLevels::Warn => "CLI_LEVELS_WARN",
+            CliLevels::Help => "CLI_LEVELS_HELP",
+            CliLevels::Data => "CLI_LEVELS_DATA",
+            CliLevels::Info => "CLI_LEVELS_INFO",
+            CliLevels::Debug => "CLI_LEVELS_DEBUG",
+            CliLevels::Prompt => "CLI_LEVELS_PROMPT",
+            CliLevels::Verbose => "CLI_LEVELS_VERBOSE",
+            CliLevels::Input => "CLI_LEVELS_INPUT",
+            CliLevels::Silly => "CLI_LEVELS_SILLY",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CLI_LEVELS_ERROR" => Some(Self::Error),
+            "CLI_LEVELS_WARN" => Some(Self::Warn),
+            "CLI_LEVELS_HELP" => Some(Self::Help),
+            "CLI_LEVELS_DATA" => Some(Self::Data),
+            "CLI_LEVELS_INFO" => Some(Self::Info),
+            "CLI_LEVELS_DEBUG" => Some(Self::Debug),
+            "CLI_LEVELS_PROMPT" => Some(Self::Prompt),
+            "CLI_LEVELS_VERBOSE" => Some(Self::Verbose),
+            "CLI_LEVELS_INPUT" => Some(Self::Input),
+            "CLI_LEVELS_SILLY" => Some(Self::Silly),
+            _ => None,
+        }
+    }
+}
 /// Encoded file descriptor set for the `pinax.substreams.sink.winston.v1` package
 pub const FILE_DESCRIPTOR_SET: &[u8] = &[
-    0x0a, 0xde, 0x0f, 0x0a, 0x0d, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f,
+    0x0a, 0xee, 0x33, 0x0a, 0x0d, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x2e, 0x70, 0x72, 0x6f,
     0x74, 0x6f, 0x12, 0x20, 0x70, 0x69, 0x6e, 0x61, 0x78, 0x2e, 0x73, 0x75, 0x62, 0x73, 0x74, 0x72,
     0x65, 0x61, 0x6d, 0x73, 0x2e, 0x73, 0x69, 0x6e, 0x6b, 0x2e, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f,
-    0x6e, 0x2e, 0x76, 0x31, 0x22, 0x65, 0x0a, 0x10, 0x4c, 0x6f, 0x67, 0x67, 0x65, 0x72, 0x4f, 0x70,
-    0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x51, 0x0a, 0x0a, 0x6f, 0x70, 0x65, 0x72,
-    0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x31, 0x2e, 0x70,
+    0x6e, 0x2e, 0x76, 0x31, 0x1a, 0x1c, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x62, 0x75, 0x66, 0x2f, 0x73, 0x74, 0x72, 0x75, 0x63, 0x74, 0x2e, 0x70, 0x72, 0x6f,
+    0x74, 0x6f, 0x1a, 0x1f, 0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2f, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x62, 0x75, 0x66, 0x2f, 0x74, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x2e, 0x70, 0x72,
+    0x6f, 0x74, 0x6f, 0x22, 0x65, 0x0a, 0x10, 0x4c, 0x6f, 0x67, 0x67, 0x65, 0x72, 0x4f, 0x70, 0x65,
+    0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x12, 0x51, 0x0a, 0x0a, 0x6f, 0x70, 0x65, 0x72, 0x61,
+    0x74, 0x69, 0x6f, 0x6e, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x31, 0x2e, 0x70, 0x69,
+    0x6e, 0x61, 0x78, 0x2e, 0x73, 0x75, 0x62, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x73, 0x2e, 0x73,
+    0x69, 0x6e, 0x6b, 0x2e, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c,
+    0x6f, 0x67, 0x67, 0x65, 0x72, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52, 0x0a,
+    0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0xd7, 0x07, 0x0a, 0x0f, 0x4c,
+    0x6f, 0x67, 0x67, 0x65, 0x72, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12, 0x18,
+    0x0a, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52,
+    0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x45, 0x0a, 0x05, 0x6c, 0x65, 0x76, 0x65,
+    0x6c, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x2f, 0x2e, 0x70, 0x69, 0x6e, 0x61, 0x78, 0x2e,
+    0x73, 0x75, 0x62, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x73, 0x2e, 0x73, 0x69, 0x6e, 0x6b, 0x2e,
+    0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x67, 0x67, 0x69,
+    0x6e, 0x67, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x52, 0x05, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x12,
+    0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28, 0x09,
+    0x52, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x62, 0x0a, 0x0b, 0x6c, 0x65, 0x67,
+    0x61, 0x63, 0x79, 0x5f, 0x6d, 0x65, 0x74, 0x61, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x41,
+    0x2e, 0x70, 0x69, 0x6e, 0x61, 0x78, 0x2e, 0x73, 0x75, 0x62, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d,
+    0x73, 0x2e, 0x73, 0x69, 0x6e, 0x6b, 0x2e, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x2e, 0x76,
+    0x31, 0x2e, 0x4c, 0x6f, 0x67, 0x67, 0x65, 0x72, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f,
+    0x6e, 0x2e, 0x4c, 0x65, 0x67, 0x61, 0x63, 0x79, 0x4d, 0x65, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72,
+    0x79, 0x52, 0x0a, 0x6c, 0x65, 0x67, 0x61, 0x63, 0x79, 0x4d, 0x65, 0x74, 0x61, 0x12, 0x3d, 0x0a,
+    0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x18, 0x05, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x27, 0x2e, 0x70,
     0x69, 0x6e, 0x61, 0x78, 0x2e, 0x73, 0x75, 0x62, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x73, 0x2e,
     0x73, 0x69, 0x6e, 0x6b, 0x2e, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e,
-    0x4c, 0x6f, 0x67, 0x67, 0x65, 0x72, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x52,
-    0x0a, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x22, 0x96, 0x02, 0x0a, 0x0f,
-    0x4c, 0x6f, 0x67, 0x67, 0x65, 0x72, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x12,
-    0x18, 0x0a, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09,
-    0x52, 0x07, 0x73, 0x65, 0x72, 0x76, 0x69, 0x63, 0x65, 0x12, 0x45, 0x0a, 0x05, 0x6c, 0x65, 0x76,
-    0x65, 0x6c, 0x18, 0x02, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x2f, 0x2e, 0x70, 0x69, 0x6e, 0x61, 0x78,
-    0x2e, 0x73, 0x75, 0x62, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x73, 0x2e, 0x73, 0x69, 0x6e, 0x6b,
-    0x2e, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x67, 0x67,
-    0x69, 0x6e, 0x67, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x52, 0x05, 0x6c, 0x65, 0x76, 0x65, 0x6c,
-    0x12, 0x18, 0x0a, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x18, 0x03, 0x20, 0x01, 0x28,
-    0x09, 0x52, 0x07, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x12, 0x4f, 0x0a, 0x04, 0x6d, 0x65,
-    0x74, 0x61, 0x18, 0x04, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x3b, 0x2e, 0x70, 0x69, 0x6e, 0x61, 0x78,
-    0x2e, 0x73, 0x75, 0x62, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x73, 0x2e, 0x73, 0x69, 0x6e, 0x6b,
-    0x2e, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x6f, 0x67, 0x67,
-    0x65, 0x72, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x2e, 0x4d, 0x65, 0x74, 0x61,
-    0x45, 0x6e, 0x74, 0x72, 0x79, 0x52, 0x04, 0x6d, 0x65, 0x74, 0x61, 0x1a, 0x37, 0x0a, 0x09, 0x4d,
+    0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x52, 0x05, 0x62, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x2b, 0x0a, 0x04,
+    0x6d, 0x65, 0x74, 0x61, 0x18, 0x06, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x17, 0x2e, 0x67, 0x6f, 0x6f,
+    0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e, 0x53, 0x74, 0x72,
+    0x75, 0x63, 0x74, 0x52, 0x04, 0x6d, 0x65, 0x74, 0x61, 0x12, 0x18, 0x0a, 0x07, 0x70, 0x72, 0x69,
+    0x76, 0x61, 0x74, 0x65, 0x18, 0x07, 0x20, 0x01, 0x28, 0x08, 0x52, 0x07, 0x70, 0x72, 0x69, 0x76,
+    0x61, 0x74, 0x65, 0x12, 0x21, 0x0a, 0x0c, 0x70, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x5f, 0x6b,
+    0x65, 0x79, 0x73, 0x18, 0x08, 0x20, 0x03, 0x28, 0x09, 0x52, 0x0b, 0x70, 0x72, 0x69, 0x76, 0x61,
+    0x74, 0x65, 0x4b, 0x65, 0x79, 0x73, 0x12, 0x48, 0x0a, 0x09, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x5f,
+    0x73, 0x65, 0x74, 0x18, 0x09, 0x20, 0x01, 0x28, 0x0e, 0x32, 0x2b, 0x2e, 0x70, 0x69, 0x6e, 0x61,
+    0x78, 0x2e, 0x73, 0x75, 0x62, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x73, 0x2e, 0x73, 0x69, 0x6e,
+    0x6b, 0x2e, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x2e, 0x76, 0x31, 0x2e, 0x4c, 0x65, 0x76,
+    0x65, 0x6c, 0x53, 0x65, 0x74, 0x73, 0x52, 0x08, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x53, 0x65, 0x74,
+    0x12, 0x1d, 0x0a, 0x0a, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x5f, 0x6e, 0x61, 0x6d, 0x65, 0x18, 0x0a,
+    0x20, 0x01, 0x28, 0x09, 0x52, 0x09, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x4e, 0x61, 0x6d, 0x65, 0x12,
+    0x2e, 0x0a, 0x10, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x68,
+    0x61, 0x73, 0x68, 0x18, 0x0c, 0x20, 0x01, 0x28, 0x09, 0x48, 0x00, 0x52, 0x0f, 0x74, 0x72, 0x61,
+    0x6e, 0x73, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x48, 0x61, 0x73, 0x68, 0x88, 0x01, 0x01, 0x12,
+    0x24, 0x0a, 0x0b, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x18, 0x0d,
+    0x20, 0x01, 0x28, 0x0d, 0x48, 0x01, 0x52, 0x0a, 0x65, 0x76, 0x65, 0x6e, 0x74, 0x49, 0x6e, 0x64,
+    0x65, 0x78, 0x88, 0x01, 0x01, 0x12, 0x1f, 0x0a, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63,
+    0x74, 0x18, 0x0e, 0x20, 0x01, 0x28, 0x09, 0x48, 0x02, 0x52, 0x08, 0x63, 0x6f, 0x6e, 0x74, 0x72,
+    0x61, 0x63, 0x74, 0x88, 0x01, 0x01, 0x12, 0x1e, 0x0a, 0x08, 0x74, 0x72, 0x61, 0x63, 0x65, 0x5f,
+    0x69, 0x64, 0x18, 0x0f, 0x20, 0x01, 0x28, 0x09, 0x48, 0x03, 0x52, 0x07, 0x74, 0x72, 0x61, 0x63,
+    0x65, 0x49, 0x64, 0x88, 0x01, 0x01, 0x12, 0x1c, 0x0a, 0x07, 0x73, 0x70, 0x61, 0x6e, 0x5f, 0x69,
+    0x64, 0x18, 0x10, 0x20, 0x01, 0x28, 0x09, 0x48, 0x04, 0x52, 0x06, 0x73, 0x70, 0x61, 0x6e, 0x49,
+    0x64, 0x88, 0x01, 0x01, 0x12, 0x25, 0x0a, 0x0b, 0x66, 0x69, 0x6e, 0x67, 0x65, 0x72, 0x70, 0x72,
+    0x69, 0x6e, 0x74, 0x18, 0x11, 0x20, 0x01, 0x28, 0x09, 0x48, 0x05, 0x52, 0x0b, 0x66, 0x69, 0x6e,
+    0x67, 0x65, 0x72, 0x70, 0x72, 0x69, 0x6e, 0x74, 0x88, 0x01, 0x01, 0x12, 0x18, 0x0a, 0x07, 0x6f,
+    0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x18, 0x12, 0x20, 0x01, 0x28, 0x04, 0x52, 0x07, 0x6f, 0x72,
+    0x64, 0x69, 0x6e, 0x61, 0x6c, 0x12, 0x2e, 0x0a, 0x10, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x5f, 0x74, 0x65, 0x6d, 0x70, 0x6c, 0x61, 0x74, 0x65, 0x18, 0x13, 0x20, 0x01, 0x28, 0x09, 0x48,
+    0x06, 0x52, 0x0f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x54, 0x65, 0x6d, 0x70, 0x6c, 0x61,
+    0x74, 0x65, 0x88, 0x01, 0x01, 0x1a, 0x3d, 0x0a, 0x0f, 0x4c, 0x65, 0x67, 0x61, 0x63, 0x79, 0x4d,
     0x65, 0x74, 0x61, 0x45, 0x6e, 0x74, 0x72, 0x79, 0x12, 0x10, 0x0a, 0x03, 0x6b, 0x65, 0x79, 0x18,
     0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x03, 0x6b, 0x65, 0x79, 0x12, 0x14, 0x0a, 0x05, 0x76, 0x61,
     0x6c, 0x75, 0x65, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x76, 0x61, 0x6c, 0x75, 0x65,
-    0x3a, 0x02, 0x38, 0x01, 0x2a, 0x68, 0x0a, 0x0d, 0x4c, 0x6f, 0x67, 0x67, 0x69, 0x6e, 0x67, 0x4c,
-    0x65, 0x76, 0x65, 0x6c, 0x73, 0x12, 0x09, 0x0a, 0x05, 0x45, 0x4d, 0x45, 0x52, 0x47, 0x10, 0x00,
-    0x12, 0x09, 0x0a, 0x05, 0x41, 0x4c, 0x45, 0x52, 0x54, 0x10, 0x01, 0x12, 0x08, 0x0a, 0x04, 0x43,
-    0x52, 0x49, 0x54, 0x10, 0x02, 0x12, 0x09, 0x0a, 0x05, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x10, 0x03,
-    0x12, 0x0b, 0x0a, 0x07, 0x57, 0x41, 0x52, 0x4e, 0x49, 0x4e, 0x47, 0x10, 0x04, 0x12, 0x0a, 0x0a,
-    0x06, 0x4e, 0x4f, 0x54, 0x49, 0x43, 0x45, 0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x49, 0x4e, 0x46,
-    0x4f, 0x10, 0x06, 0x12, 0x09, 0x0a, 0x05, 0x44, 0x45, 0x42, 0x55, 0x47, 0x10, 0x07, 0x42, 0x3a,
-    0x5a, 0x38, 0x67, 0x69, 0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x70, 0x69, 0x6e,
-    0x61, 0x78, 0x2d, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2f, 0x73, 0x75, 0x62, 0x73, 0x74,
-    0x72, 0x65, 0x61, 0x6d, 0x73, 0x2d, 0x73, 0x69, 0x6e, 0x6b, 0x2d, 0x77, 0x69, 0x6e, 0x73, 0x74,
-    0x6f, 0x6e, 0x2f, 0x70, 0x62, 0x3b, 0x70, 0x62, 0x6b, 0x76, 0x4a, 0xfc, 0x0a, 0x0a, 0x06, 0x12,
-    0x04, 0x00, 0x00, 0x20, 0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a,
-    0x08, 0x0a, 0x01, 0x02, 0x12, 0x03, 0x02, 0x00, 0x29, 0x0a, 0x08, 0x0a, 0x01, 0x08, 0x12, 0x03,
-    0x04, 0x00, 0x4f, 0x0a, 0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x04, 0x00, 0x4f, 0x0a, 0x30,
-    0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x07, 0x00, 0x09, 0x01, 0x1a, 0x24, 0x20, 0x56, 0x65, 0x63,
-    0x74, 0x6f, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x57, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x20, 0x4c,
-    0x6f, 0x67, 0x67, 0x69, 0x6e, 0x67, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x0a,
-    0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x07, 0x08, 0x18, 0x0a, 0x0b, 0x0a, 0x04,
-    0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x08, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
-    0x00, 0x04, 0x12, 0x03, 0x08, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x06,
-    0x12, 0x03, 0x08, 0x0b, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03,
-    0x08, 0x1b, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03, 0x08, 0x28,
-    0x29, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0b, 0x00, 0x10, 0x01, 0x0a, 0x0a, 0x0a,
-    0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0b, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
-    0x00, 0x12, 0x03, 0x0c, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x05, 0x12,
-    0x03, 0x0c, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x0c,
-    0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0c, 0x13, 0x14,
-    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x0d, 0x02, 0x1a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x0d, 0x02, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x0d, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x01, 0x03, 0x12, 0x03, 0x0d, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x02, 0x12,
-    0x03, 0x0e, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12, 0x03, 0x0e,
-    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x0e, 0x09, 0x10,
-    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x0e, 0x13, 0x14, 0x0a, 0x0b,
-    0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x0f, 0x02, 0x1f, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
-    0x01, 0x02, 0x03, 0x06, 0x12, 0x03, 0x0f, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
-    0x03, 0x01, 0x12, 0x03, 0x0f, 0x16, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03,
-    0x12, 0x03, 0x0f, 0x1d, 0x1e, 0x0a, 0xb0, 0x02, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x16, 0x00,
-    0x20, 0x01, 0x1a, 0xa3, 0x02, 0x20, 0x45, 0x61, 0x63, 0x68, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c,
-    0x20, 0x69, 0x73, 0x20, 0x67, 0x69, 0x76, 0x65, 0x6e, 0x20, 0x61, 0x20, 0x73, 0x70, 0x65, 0x63,
-    0x69, 0x66, 0x69, 0x63, 0x20, 0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x20, 0x70, 0x72, 0x69,
-    0x6f, 0x72, 0x69, 0x74, 0x79, 0x2e, 0x0a, 0x20, 0x54, 0x68, 0x65, 0x20, 0x68, 0x69, 0x67, 0x68,
-    0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x20,
-    0x74, 0x68, 0x65, 0x20, 0x6d, 0x6f, 0x72, 0x65, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x61,
-    0x6e, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x69,
-    0x73, 0x20, 0x63, 0x6f, 0x6e, 0x73, 0x69, 0x64, 0x65, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20,
-    0x62, 0x65, 0x2c, 0x0a, 0x20, 0x61, 0x6e, 0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x77,
-    0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20, 0x63, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e,
-    0x64, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e, 0x74, 0x65, 0x67, 0x65, 0x72, 0x20, 0x70, 0x72, 0x69,
-    0x6f, 0x72, 0x69, 0x74, 0x79, 0x2e, 0x0a, 0x20, 0x46, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x61, 0x6d,
-    0x70, 0x6c, 0x65, 0x2c, 0x20, 0x61, 0x73, 0x20, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65,
-    0x64, 0x20, 0x65, 0x78, 0x61, 0x63, 0x74, 0x6c, 0x79, 0x20, 0x69, 0x6e, 0x20, 0x52, 0x46, 0x43,
-    0x35, 0x34, 0x32, 0x34, 0x20, 0x74, 0x68, 0x65, 0x20, 0x73, 0x79, 0x73, 0x6c, 0x6f, 0x67, 0x20,
-    0x6c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72,
-    0x69, 0x74, 0x69, 0x7a, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x30, 0x20, 0x74, 0x6f,
-    0x20, 0x37, 0x20, 0x28, 0x68, 0x69, 0x67, 0x68, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x6c,
-    0x6f, 0x77, 0x65, 0x73, 0x74, 0x29, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12,
-    0x03, 0x16, 0x05, 0x12, 0x0a, 0x5e, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x18, 0x02,
-    0x0c, 0x1a, 0x30, 0x20, 0x55, 0x4e, 0x53, 0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x20,
-    0x3d, 0x20, 0x30, 0x3b, 0x20, 0x2f, 0x2f, 0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66,
-    0x69, 0x65, 0x64, 0x3a, 0x20, 0x64, 0x65, 0x66, 0x61, 0x75, 0x6c, 0x74, 0x20, 0x76, 0x61, 0x6c,
-    0x75, 0x65, 0x0a, 0x22, 0x1f, 0x20, 0x45, 0x6d, 0x65, 0x72, 0x67, 0x65, 0x6e, 0x63, 0x79, 0x3a,
-    0x20, 0x73, 0x79, 0x73, 0x74, 0x65, 0x6d, 0x20, 0x69, 0x73, 0x20, 0x75, 0x6e, 0x75, 0x73, 0x61,
-    0x62, 0x6c, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x18,
-    0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x18, 0x0a, 0x0b,
-    0x0a, 0x36, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x01, 0x12, 0x03, 0x19, 0x02, 0x0c, 0x22, 0x29, 0x20,
-    0x41, 0x6c, 0x65, 0x72, 0x74, 0x3a, 0x20, 0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6d, 0x75,
-    0x73, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74, 0x61, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x6d, 0x6d, 0x65,
-    0x64, 0x69, 0x61, 0x74, 0x65, 0x6c, 0x79, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01,
-    0x01, 0x12, 0x03, 0x19, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12,
-    0x03, 0x19, 0x0a, 0x0b, 0x0a, 0x2c, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x02,
-    0x0b, 0x22, 0x1f, 0x20, 0x43, 0x72, 0x69, 0x74, 0x69, 0x63, 0x61, 0x6c, 0x3a, 0x20, 0x63, 0x72,
-    0x69, 0x74, 0x69, 0x63, 0x61, 0x6c, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e,
-    0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x1a, 0x02, 0x06,
-    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x02, 0x02, 0x12, 0x03, 0x1a, 0x09, 0x0a, 0x0a, 0x26,
-    0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12, 0x03, 0x1b, 0x02, 0x0c, 0x22, 0x19, 0x20, 0x45, 0x72,
-    0x72, 0x6f, 0x72, 0x3a, 0x20, 0x65, 0x72, 0x72, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69,
-    0x74, 0x69, 0x6f, 0x6e, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12,
-    0x03, 0x1b, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x1b,
-    0x0a, 0x0b, 0x0a, 0x2a, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x04, 0x12, 0x03, 0x1c, 0x02, 0x0e, 0x22,
-    0x1d, 0x20, 0x57, 0x61, 0x72, 0x6e, 0x69, 0x6e, 0x67, 0x3a, 0x20, 0x77, 0x61, 0x72, 0x6e, 0x69,
-    0x6e, 0x67, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x0a, 0x0a, 0x0c,
-    0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01, 0x12, 0x03, 0x1c, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05,
-    0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03, 0x1c, 0x0c, 0x0d, 0x0a, 0x37, 0x0a, 0x04, 0x05, 0x00,
-    0x02, 0x05, 0x12, 0x03, 0x1d, 0x02, 0x0d, 0x22, 0x2a, 0x20, 0x4e, 0x6f, 0x74, 0x69, 0x63, 0x65,
-    0x3a, 0x20, 0x6e, 0x6f, 0x72, 0x6d, 0x61, 0x6c, 0x20, 0x62, 0x75, 0x74, 0x20, 0x73, 0x69, 0x67,
-    0x6e, 0x69, 0x66, 0x69, 0x63, 0x61, 0x6e, 0x74, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69,
-    0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x1d, 0x02,
-    0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x05, 0x02, 0x12, 0x03, 0x1d, 0x0b, 0x0c, 0x0a,
-    0x34, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x06, 0x12, 0x03, 0x1e, 0x02, 0x0b, 0x22, 0x27, 0x20, 0x49,
-    0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x3a, 0x20, 0x69, 0x6e,
-    0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x6d, 0x65, 0x73, 0x73,
-    0x61, 0x67, 0x65, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03,
-    0x1e, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x1e, 0x09,
-    0x0a, 0x0a, 0x2a, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x07, 0x12, 0x03, 0x1f, 0x02, 0x0c, 0x22, 0x1d,
-    0x20, 0x44, 0x65, 0x62, 0x75, 0x67, 0x3a, 0x20, 0x64, 0x65, 0x62, 0x75, 0x67, 0x2d, 0x6c, 0x65,
-    0x76, 0x65, 0x6c, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x0a, 0x0a, 0x0c, 0x0a,
-    0x05, 0x05, 0x00, 0x02, 0x07, 0x01, 0x12, 0x03, 0x1f, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
-    0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x1f, 0x0a, 0x0b, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
+    0x3a, 0x02, 0x38, 0x01, 0x42, 0x13, 0x0a, 0x11, 0x5f, 0x74, 0x72, 0x61, 0x6e, 0x73, 0x61, 0x63,
+    0x74, 0x69, 0x6f, 0x6e, 0x5f, 0x68, 0x61, 0x73, 0x68, 0x42, 0x0e, 0x0a, 0x0c, 0x5f, 0x65, 0x76,
+    0x65, 0x6e, 0x74, 0x5f, 0x69, 0x6e, 0x64, 0x65, 0x78, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x63, 0x6f,
+    0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x42, 0x0b, 0x0a, 0x09, 0x5f, 0x74, 0x72, 0x61, 0x63, 0x65,
+    0x5f, 0x69, 0x64, 0x42, 0x0a, 0x0a, 0x08, 0x5f, 0x73, 0x70, 0x61, 0x6e, 0x5f, 0x69, 0x64, 0x42,
+    0x0e, 0x0a, 0x0c, 0x5f, 0x66, 0x69, 0x6e, 0x67, 0x65, 0x72, 0x70, 0x72, 0x69, 0x6e, 0x74, 0x42,
+    0x13, 0x0a, 0x11, 0x5f, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x5f, 0x74, 0x65, 0x6d, 0x70,
+    0x6c, 0x61, 0x74, 0x65, 0x22, 0x8e, 0x01, 0x0a, 0x05, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x12, 0x12,
+    0x0a, 0x04, 0x68, 0x61, 0x73, 0x68, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x04, 0x68, 0x61,
+    0x73, 0x68, 0x12, 0x16, 0x0a, 0x06, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x18, 0x02, 0x20, 0x01,
+    0x28, 0x04, 0x52, 0x06, 0x6e, 0x75, 0x6d, 0x62, 0x65, 0x72, 0x12, 0x38, 0x0a, 0x09, 0x74, 0x69,
+    0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x18, 0x03, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x1a, 0x2e,
+    0x67, 0x6f, 0x6f, 0x67, 0x6c, 0x65, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x75, 0x66, 0x2e,
+    0x54, 0x69, 0x6d, 0x65, 0x73, 0x74, 0x61, 0x6d, 0x70, 0x52, 0x09, 0x74, 0x69, 0x6d, 0x65, 0x73,
+    0x74, 0x61, 0x6d, 0x70, 0x12, 0x1f, 0x0a, 0x0b, 0x70, 0x61, 0x72, 0x65, 0x6e, 0x74, 0x5f, 0x68,
+    0x61, 0x73, 0x68, 0x18, 0x04, 0x20, 0x01, 0x28, 0x09, 0x52, 0x0a, 0x70, 0x61, 0x72, 0x65, 0x6e,
+    0x74, 0x48, 0x61, 0x73, 0x68, 0x2a, 0x68, 0x0a, 0x0d, 0x4c, 0x6f, 0x67, 0x67, 0x69, 0x6e, 0x67,
+    0x4c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x12, 0x09, 0x0a, 0x05, 0x45, 0x4d, 0x45, 0x52, 0x47, 0x10,
+    0x00, 0x12, 0x09, 0x0a, 0x05, 0x41, 0x4c, 0x45, 0x52, 0x54, 0x10, 0x01, 0x12, 0x08, 0x0a, 0x04,
+    0x43, 0x52, 0x49, 0x54, 0x10, 0x02, 0x12, 0x09, 0x0a, 0x05, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x10,
+    0x03, 0x12, 0x0b, 0x0a, 0x07, 0x57, 0x41, 0x52, 0x4e, 0x49, 0x4e, 0x47, 0x10, 0x04, 0x12, 0x0a,
+    0x0a, 0x06, 0x4e, 0x4f, 0x54, 0x49, 0x43, 0x45, 0x10, 0x05, 0x12, 0x08, 0x0a, 0x04, 0x49, 0x4e,
+    0x46, 0x4f, 0x10, 0x06, 0x12, 0x09, 0x0a, 0x05, 0x44, 0x45, 0x42, 0x55, 0x47, 0x10, 0x07, 0x2a,
+    0x29, 0x0a, 0x09, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x53, 0x65, 0x74, 0x73, 0x12, 0x0a, 0x0a, 0x06,
+    0x53, 0x59, 0x53, 0x4c, 0x4f, 0x47, 0x10, 0x00, 0x12, 0x07, 0x0a, 0x03, 0x4e, 0x50, 0x4d, 0x10,
+    0x01, 0x12, 0x07, 0x0a, 0x03, 0x43, 0x4c, 0x49, 0x10, 0x02, 0x2a, 0xa4, 0x01, 0x0a, 0x09, 0x4e,
+    0x70, 0x6d, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x12, 0x14, 0x0a, 0x10, 0x4e, 0x50, 0x4d, 0x5f,
+    0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x45, 0x52, 0x52, 0x4f, 0x52, 0x10, 0x00, 0x12, 0x13,
+    0x0a, 0x0f, 0x4e, 0x50, 0x4d, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x57, 0x41, 0x52,
+    0x4e, 0x10, 0x01, 0x12, 0x13, 0x0a, 0x0f, 0x4e, 0x50, 0x4d, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c,
+    0x53, 0x5f, 0x49, 0x4e, 0x46, 0x4f, 0x10, 0x02, 0x12, 0x13, 0x0a, 0x0f, 0x4e, 0x50, 0x4d, 0x5f,
+    0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x48, 0x54, 0x54, 0x50, 0x10, 0x03, 0x12, 0x16, 0x0a,
+    0x12, 0x4e, 0x50, 0x4d, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x56, 0x45, 0x52, 0x42,
+    0x4f, 0x53, 0x45, 0x10, 0x04, 0x12, 0x14, 0x0a, 0x10, 0x4e, 0x50, 0x4d, 0x5f, 0x4c, 0x45, 0x56,
+    0x45, 0x4c, 0x53, 0x5f, 0x44, 0x45, 0x42, 0x55, 0x47, 0x10, 0x05, 0x12, 0x14, 0x0a, 0x10, 0x4e,
+    0x50, 0x4d, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x53, 0x49, 0x4c, 0x4c, 0x59, 0x10,
+    0x06, 0x2a, 0xe6, 0x01, 0x0a, 0x09, 0x43, 0x6c, 0x69, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x12,
+    0x14, 0x0a, 0x10, 0x43, 0x4c, 0x49, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x45, 0x52,
+    0x52, 0x4f, 0x52, 0x10, 0x00, 0x12, 0x13, 0x0a, 0x0f, 0x43, 0x4c, 0x49, 0x5f, 0x4c, 0x45, 0x56,
+    0x45, 0x4c, 0x53, 0x5f, 0x57, 0x41, 0x52, 0x4e, 0x10, 0x01, 0x12, 0x13, 0x0a, 0x0f, 0x43, 0x4c,
+    0x49, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x48, 0x45, 0x4c, 0x50, 0x10, 0x02, 0x12,
+    0x13, 0x0a, 0x0f, 0x43, 0x4c, 0x49, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x44, 0x41,
+    0x54, 0x41, 0x10, 0x03, 0x12, 0x13, 0x0a, 0x0f, 0x43, 0x4c, 0x49, 0x5f, 0x4c, 0x45, 0x56, 0x45,
+    0x4c, 0x53, 0x5f, 0x49, 0x4e, 0x46, 0x4f, 0x10, 0x04, 0x12, 0x14, 0x0a, 0x10, 0x43, 0x4c, 0x49,
+    0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x44, 0x45, 0x42, 0x55, 0x47, 0x10, 0x05, 0x12,
+    0x15, 0x0a, 0x11, 0x43, 0x4c, 0x49, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x50, 0x52,
+    0x4f, 0x4d, 0x50, 0x54, 0x10, 0x06, 0x12, 0x16, 0x0a, 0x12, 0x43, 0x4c, 0x49, 0x5f, 0x4c, 0x45,
+    0x56, 0x45, 0x4c, 0x53, 0x5f, 0x56, 0x45, 0x52, 0x42, 0x4f, 0x53, 0x45, 0x10, 0x07, 0x12, 0x14,
+    0x0a, 0x10, 0x43, 0x4c, 0x49, 0x5f, 0x4c, 0x45, 0x56, 0x45, 0x4c, 0x53, 0x5f, 0x49, 0x4e, 0x50,
+    0x55, 0x54, 0x10, 0x08, 0x12, 0x14, 0x0a, 0x10, 0x43, 0x4c, 0x49, 0x5f, 0x4c, 0x45, 0x56, 0x45,
+    0x4c, 0x53, 0x5f, 0x53, 0x49, 0x4c, 0x4c, 0x59, 0x10, 0x09, 0x42, 0x3a, 0x5a, 0x38, 0x67, 0x69,
+    0x74, 0x68, 0x75, 0x62, 0x2e, 0x63, 0x6f, 0x6d, 0x2f, 0x70, 0x69, 0x6e, 0x61, 0x78, 0x2d, 0x6e,
+    0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b, 0x2f, 0x73, 0x75, 0x62, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d,
+    0x73, 0x2d, 0x73, 0x69, 0x6e, 0x6b, 0x2d, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x2f, 0x70,
+    0x62, 0x3b, 0x70, 0x62, 0x6b, 0x76, 0x4a, 0xc0, 0x24, 0x0a, 0x06, 0x12, 0x04, 0x00, 0x00, 0x61,
+    0x01, 0x0a, 0x08, 0x0a, 0x01, 0x0c, 0x12, 0x03, 0x00, 0x00, 0x12, 0x0a, 0x08, 0x0a, 0x01, 0x02,
+    0x12, 0x03, 0x02, 0x00, 0x29, 0x0a, 0x09, 0x0a, 0x02, 0x03, 0x00, 0x12, 0x03, 0x04, 0x00, 0x26,
+    0x0a, 0x09, 0x0a, 0x02, 0x03, 0x01, 0x12, 0x03, 0x05, 0x00, 0x29, 0x0a, 0x08, 0x0a, 0x01, 0x08,
+    0x12, 0x03, 0x07, 0x00, 0x4f, 0x0a, 0x09, 0x0a, 0x02, 0x08, 0x0b, 0x12, 0x03, 0x07, 0x00, 0x4f,
+    0x0a, 0x30, 0x0a, 0x02, 0x04, 0x00, 0x12, 0x04, 0x0a, 0x00, 0x0c, 0x01, 0x1a, 0x24, 0x20, 0x56,
+    0x65, 0x63, 0x74, 0x6f, 0x72, 0x20, 0x6f, 0x66, 0x20, 0x57, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e,
+    0x20, 0x4c, 0x6f, 0x67, 0x67, 0x69, 0x6e, 0x67, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x73, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x00, 0x01, 0x12, 0x03, 0x0a, 0x08, 0x18, 0x0a, 0x0b,
+    0x0a, 0x04, 0x04, 0x00, 0x02, 0x00, 0x12, 0x03, 0x0b, 0x02, 0x2a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x00, 0x02, 0x00, 0x04, 0x12, 0x03, 0x0b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02,
+    0x00, 0x06, 0x12, 0x03, 0x0b, 0x0b, 0x1a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x01,
+    0x12, 0x03, 0x0b, 0x1b, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x00, 0x02, 0x00, 0x03, 0x12, 0x03,
+    0x0b, 0x28, 0x29, 0x0a, 0x0a, 0x0a, 0x02, 0x04, 0x01, 0x12, 0x04, 0x0e, 0x00, 0x28, 0x01, 0x0a,
+    0x0a, 0x0a, 0x03, 0x04, 0x01, 0x01, 0x12, 0x03, 0x0e, 0x08, 0x17, 0x0a, 0x0b, 0x0a, 0x04, 0x04,
+    0x01, 0x02, 0x00, 0x12, 0x03, 0x0f, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00,
+    0x05, 0x12, 0x03, 0x0f, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x0f, 0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x00, 0x03, 0x12, 0x03, 0x0f,
+    0x13, 0x14, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x01, 0x12, 0x03, 0x10, 0x02, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x01, 0x06, 0x12, 0x03, 0x10, 0x02, 0x0f, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x10, 0x10, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x01, 0x03, 0x12, 0x03, 0x10, 0x18, 0x19, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02,
+    0x02, 0x12, 0x03, 0x11, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x05, 0x12,
+    0x03, 0x11, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x01, 0x12, 0x03, 0x11,
+    0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x02, 0x03, 0x12, 0x03, 0x11, 0x13, 0x14,
+    0x0a, 0x45, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x03, 0x12, 0x03, 0x12, 0x02, 0x26, 0x22, 0x38, 0x20,
+    0x44, 0x65, 0x70, 0x72, 0x65, 0x63, 0x61, 0x74, 0x65, 0x64, 0x3a, 0x20, 0x73, 0x74, 0x72, 0x69,
+    0x6e, 0x67, 0x2d, 0x6f, 0x6e, 0x6c, 0x79, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x64, 0x61, 0x74, 0x61,
+    0x2c, 0x20, 0x73, 0x75, 0x70, 0x65, 0x72, 0x73, 0x65, 0x64, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20,
+    0x60, 0x6d, 0x65, 0x74, 0x61, 0x60, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x06,
+    0x12, 0x03, 0x12, 0x02, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x01, 0x12, 0x03,
+    0x12, 0x16, 0x21, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x03, 0x03, 0x12, 0x03, 0x12, 0x24,
+    0x25, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x04, 0x12, 0x03, 0x13, 0x02, 0x12, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x04, 0x06, 0x12, 0x03, 0x13, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x04, 0x01, 0x12, 0x03, 0x13, 0x08, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x04, 0x03, 0x12, 0x03, 0x13, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x05,
+    0x12, 0x03, 0x14, 0x02, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x06, 0x12, 0x03,
+    0x14, 0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x01, 0x12, 0x03, 0x14, 0x19,
+    0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x05, 0x03, 0x12, 0x03, 0x14, 0x20, 0x21, 0x0a,
+    0x41, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x06, 0x12, 0x03, 0x15, 0x02, 0x13, 0x22, 0x34, 0x20, 0x50,
+    0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x20, 0x6f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e,
+    0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x70, 0x70, 0x65, 0x64, 0x20, 0x62,
+    0x79, 0x20, 0x60, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x50, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65,
+    0x60, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x05, 0x12, 0x03, 0x15, 0x02, 0x06,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x01, 0x12, 0x03, 0x15, 0x07, 0x0e, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x06, 0x03, 0x12, 0x03, 0x15, 0x11, 0x12, 0x0a, 0x42, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x07, 0x12, 0x03, 0x16, 0x02, 0x23, 0x22, 0x35, 0x20, 0x50, 0x72, 0x69, 0x76,
+    0x61, 0x74, 0x65, 0x20, 0x60, 0x6d, 0x65, 0x74, 0x61, 0x60, 0x20, 0x6b, 0x65, 0x79, 0x73, 0x20,
+    0x61, 0x72, 0x65, 0x20, 0x73, 0x74, 0x72, 0x69, 0x70, 0x70, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20,
+    0x60, 0x69, 0x67, 0x6e, 0x6f, 0x72, 0x65, 0x50, 0x72, 0x69, 0x76, 0x61, 0x74, 0x65, 0x60, 0x0a,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x04, 0x12, 0x03, 0x16, 0x02, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x07, 0x05, 0x12, 0x03, 0x16, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x04, 0x01, 0x02, 0x07, 0x01, 0x12, 0x03, 0x16, 0x12, 0x1e, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x07, 0x03, 0x12, 0x03, 0x16, 0x21, 0x22, 0x0a, 0x40, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x08,
+    0x12, 0x03, 0x17, 0x02, 0x1a, 0x22, 0x33, 0x20, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x20, 0x73, 0x65,
+    0x74, 0x20, 0x6f, 0x66, 0x20, 0x74, 0x68, 0x65, 0x20, 0x4c, 0x6f, 0x67, 0x67, 0x65, 0x72, 0x20,
+    0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74, 0x65, 0x64, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01,
+    0x02, 0x08, 0x06, 0x12, 0x03, 0x17, 0x02, 0x0b, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08,
+    0x01, 0x12, 0x03, 0x17, 0x0c, 0x15, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x08, 0x03, 0x12,
+    0x03, 0x17, 0x18, 0x19, 0x0a, 0x41, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x09, 0x12, 0x03, 0x18, 0x02,
+    0x19, 0x22, 0x34, 0x20, 0x57, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x20, 0x6c, 0x65, 0x76, 0x65,
+    0x6c, 0x20, 0x6e, 0x61, 0x6d, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x60, 0x6c,
+    0x65, 0x76, 0x65, 0x6c, 0x5f, 0x73, 0x65, 0x74, 0x60, 0x20, 0x28, 0x65, 0x78, 0x3a, 0x20, 0x22,
+    0x77, 0x61, 0x72, 0x6e, 0x22, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x05,
+    0x12, 0x03, 0x18, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x01, 0x12, 0x03,
+    0x18, 0x09, 0x13, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x09, 0x03, 0x12, 0x03, 0x18, 0x16,
+    0x18, 0x0a, 0x56, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0a, 0x12, 0x03, 0x1b, 0x02, 0x28, 0x1a, 0x49,
+    0x20, 0x50, 0x72, 0x6f, 0x76, 0x65, 0x6e, 0x61, 0x6e, 0x63, 0x65, 0x20, 0x6f, 0x66, 0x20, 0x74,
+    0x68, 0x65, 0x20, 0x57, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x20, 0x4c, 0x6f, 0x67, 0x67, 0x69,
+    0x6e, 0x67, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x2c, 0x20, 0x68, 0x65, 0x78, 0x20,
+    0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x60, 0x30, 0x78,
+    0x60, 0x20, 0x70, 0x72, 0x65, 0x66, 0x69, 0x78, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x0a, 0x04, 0x12, 0x03, 0x1b, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x05,
+    0x12, 0x03, 0x1b, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x01, 0x12, 0x03,
+    0x1b, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0a, 0x03, 0x12, 0x03, 0x1b, 0x25,
+    0x27, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0b, 0x12, 0x03, 0x1c, 0x02, 0x23, 0x22, 0x2f,
+    0x20, 0x45, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x20, 0x6c, 0x6f, 0x67, 0x20, 0x69, 0x6e,
+    0x64, 0x65, 0x78, 0x20, 0x6f, 0x72, 0x20, 0x41, 0x6e, 0x74, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x20,
+    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x72, 0x64, 0x69, 0x6e, 0x61, 0x6c, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0b, 0x04, 0x12, 0x03, 0x1c, 0x02, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x01, 0x02, 0x0b, 0x05, 0x12, 0x03, 0x1c, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x0b, 0x01, 0x12, 0x03, 0x1c, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x0b, 0x03, 0x12, 0x03, 0x1c, 0x20, 0x22, 0x0a, 0x3c, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x0c, 0x12,
+    0x03, 0x1d, 0x02, 0x20, 0x22, 0x2f, 0x20, 0x45, 0x74, 0x68, 0x65, 0x72, 0x65, 0x75, 0x6d, 0x20,
+    0x63, 0x6f, 0x6e, 0x74, 0x72, 0x61, 0x63, 0x74, 0x20, 0x61, 0x64, 0x64, 0x72, 0x65, 0x73, 0x73,
+    0x20, 0x6f, 0x72, 0x20, 0x41, 0x6e, 0x74, 0x65, 0x6c, 0x6f, 0x70, 0x65, 0x20, 0x61, 0x63, 0x63,
+    0x6f, 0x75, 0x6e, 0x74, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0c, 0x04, 0x12, 0x03,
+    0x1d, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0c, 0x05, 0x12, 0x03, 0x1d, 0x0b,
+    0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0c, 0x01, 0x12, 0x03, 0x1d, 0x12, 0x1a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0c, 0x03, 0x12, 0x03, 0x1d, 0x1d, 0x1f, 0x0a, 0x65, 0x0a,
+    0x04, 0x04, 0x01, 0x02, 0x0d, 0x12, 0x03, 0x20, 0x02, 0x20, 0x1a, 0x4c, 0x20, 0x4f, 0x70, 0x65,
+    0x6e, 0x54, 0x65, 0x6c, 0x65, 0x6d, 0x65, 0x74, 0x72, 0x79, 0x20, 0x74, 0x72, 0x61, 0x63, 0x65,
+    0x20, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78, 0x74, 0x2c, 0x20, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x63,
+    0x61, 0x73, 0x65, 0x20, 0x68, 0x65, 0x78, 0x20, 0x65, 0x6e, 0x63, 0x6f, 0x64, 0x65, 0x64, 0x20,
+    0x61, 0x73, 0x20, 0x69, 0x6e, 0x20, 0x57, 0x33, 0x43, 0x20, 0x60, 0x74, 0x72, 0x61, 0x63, 0x65,
+    0x70, 0x61, 0x72, 0x65, 0x6e, 0x74, 0x60, 0x0a, 0x22, 0x0a, 0x20, 0x31, 0x36, 0x20, 0x62, 0x79,
+    0x74, 0x65, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x04, 0x12, 0x03, 0x20,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x05, 0x12, 0x03, 0x20, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x01, 0x12, 0x03, 0x20, 0x12, 0x1a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x0d, 0x03, 0x12, 0x03, 0x20, 0x1d, 0x1f, 0x0a, 0x16, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x0e, 0x12, 0x03, 0x21, 0x02, 0x1f, 0x22, 0x09, 0x20, 0x38, 0x20, 0x62, 0x79,
+    0x74, 0x65, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x04, 0x12, 0x03, 0x21,
+    0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x05, 0x12, 0x03, 0x21, 0x0b, 0x11,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x01, 0x12, 0x03, 0x21, 0x12, 0x19, 0x0a, 0x0c,
+    0x0a, 0x05, 0x04, 0x01, 0x02, 0x0e, 0x03, 0x12, 0x03, 0x21, 0x1c, 0x1e, 0x0a, 0x4b, 0x0a, 0x04,
+    0x04, 0x01, 0x02, 0x0f, 0x12, 0x03, 0x23, 0x02, 0x23, 0x22, 0x3e, 0x20, 0x53, 0x74, 0x61, 0x62,
+    0x6c, 0x65, 0x20, 0x68, 0x61, 0x73, 0x68, 0x20, 0x6f, 0x66, 0x20, 0x73, 0x65, 0x72, 0x76, 0x69,
+    0x63, 0x65, 0x2c, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x2c, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61,
+    0x67, 0x65, 0x20, 0x26, 0x20, 0x6d, 0x65, 0x74, 0x61, 0x2c, 0x20, 0x75, 0x73, 0x65, 0x64, 0x20,
+    0x74, 0x6f, 0x20, 0x64, 0x65, 0x64, 0x75, 0x70, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x0f, 0x04, 0x12, 0x03, 0x23, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0f, 0x05,
+    0x12, 0x03, 0x23, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0f, 0x01, 0x12, 0x03,
+    0x23, 0x12, 0x1d, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x0f, 0x03, 0x12, 0x03, 0x23, 0x20,
+    0x22, 0x0a, 0x53, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x10, 0x12, 0x03, 0x24, 0x02, 0x16, 0x22, 0x46,
+    0x20, 0x45, 0x6d, 0x69, 0x73, 0x73, 0x69, 0x6f, 0x6e, 0x20, 0x6f, 0x72, 0x64, 0x65, 0x72, 0x20,
+    0x77, 0x69, 0x74, 0x68, 0x69, 0x6e, 0x20, 0x74, 0x68, 0x65, 0x20, 0x62, 0x6c, 0x6f, 0x63, 0x6b,
+    0x2c, 0x20, 0x61, 0x73, 0x73, 0x69, 0x67, 0x6e, 0x65, 0x64, 0x20, 0x62, 0x79, 0x20, 0x60, 0x4c,
+    0x6f, 0x67, 0x67, 0x65, 0x72, 0x4f, 0x70, 0x65, 0x72, 0x61, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x60,
+    0x20, 0x70, 0x75, 0x73, 0x68, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x05, 0x12,
+    0x03, 0x24, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x01, 0x12, 0x03, 0x24,
+    0x09, 0x10, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x10, 0x03, 0x12, 0x03, 0x24, 0x13, 0x15,
+    0x0a, 0x69, 0x0a, 0x04, 0x04, 0x01, 0x02, 0x11, 0x12, 0x03, 0x27, 0x02, 0x28, 0x1a, 0x5c, 0x20,
+    0x43, 0x6f, 0x6e, 0x73, 0x74, 0x61, 0x6e, 0x74, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65,
+    0x20, 0x74, 0x65, 0x6d, 0x70, 0x6c, 0x61, 0x74, 0x65, 0x20, 0x28, 0x65, 0x78, 0x3a, 0x20, 0x22,
+    0x53, 0x77, 0x61, 0x70, 0x20, 0x6f, 0x66, 0x20, 0x7b, 0x61, 0x6d, 0x6f, 0x75, 0x6e, 0x74, 0x7d,
+    0x20, 0x6f, 0x6e, 0x20, 0x7b, 0x70, 0x6f, 0x6f, 0x6c, 0x7d, 0x22, 0x29, 0x2c, 0x20, 0x61, 0x72,
+    0x67, 0x75, 0x6d, 0x65, 0x6e, 0x74, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x68, 0x65, 0x6c, 0x64,
+    0x20, 0x69, 0x6e, 0x20, 0x60, 0x6d, 0x65, 0x74, 0x61, 0x60, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x01, 0x02, 0x11, 0x04, 0x12, 0x03, 0x27, 0x02, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02,
+    0x11, 0x05, 0x12, 0x03, 0x27, 0x0b, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x11, 0x01,
+    0x12, 0x03, 0x27, 0x12, 0x22, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x01, 0x02, 0x11, 0x03, 0x12, 0x03,
+    0x27, 0x25, 0x27, 0x0a, 0x90, 0x01, 0x0a, 0x02, 0x04, 0x02, 0x12, 0x04, 0x2c, 0x00, 0x31, 0x01,
+    0x1a, 0x83, 0x01, 0x20, 0x42, 0x6c, 0x6f, 0x63, 0x6b, 0x20, 0x63, 0x6f, 0x6e, 0x74, 0x65, 0x78,
+    0x74, 0x20, 0x69, 0x6e, 0x20, 0x77, 0x68, 0x69, 0x63, 0x68, 0x20, 0x74, 0x68, 0x65, 0x20, 0x57,
+    0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x20, 0x4c, 0x6f, 0x67, 0x67, 0x69, 0x6e, 0x67, 0x20, 0x6d,
+    0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x77, 0x61, 0x73, 0x20, 0x65, 0x6d, 0x69, 0x74, 0x74,
+    0x65, 0x64, 0x2e, 0x0a, 0x20, 0x46, 0x69, 0x65, 0x6c, 0x64, 0x73, 0x20, 0x31, 0x20, 0x74, 0x6f,
+    0x20, 0x33, 0x20, 0x61, 0x72, 0x65, 0x20, 0x77, 0x69, 0x72, 0x65, 0x2d, 0x63, 0x6f, 0x6d, 0x70,
+    0x61, 0x74, 0x69, 0x62, 0x6c, 0x65, 0x20, 0x77, 0x69, 0x74, 0x68, 0x20, 0x60, 0x73, 0x66, 0x2e,
+    0x73, 0x75, 0x62, 0x73, 0x74, 0x72, 0x65, 0x61, 0x6d, 0x73, 0x2e, 0x76, 0x31, 0x2e, 0x43, 0x6c,
+    0x6f, 0x63, 0x6b, 0x60, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x04, 0x02, 0x01, 0x12, 0x03, 0x2c,
+    0x08, 0x0d, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x00, 0x12, 0x03, 0x2d, 0x02, 0x12, 0x0a,
+    0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x00, 0x05, 0x12, 0x03, 0x2d, 0x02, 0x08, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x00, 0x01, 0x12, 0x03, 0x2d, 0x09, 0x0d, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x00, 0x03, 0x12, 0x03, 0x2d, 0x10, 0x11, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x2e, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x05, 0x12,
+    0x03, 0x2e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x2e,
+    0x09, 0x0f, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x01, 0x03, 0x12, 0x03, 0x2e, 0x12, 0x13,
+    0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x02, 0x12, 0x03, 0x2f, 0x02, 0x2a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x04, 0x02, 0x02, 0x02, 0x06, 0x12, 0x03, 0x2f, 0x02, 0x1b, 0x0a, 0x0c, 0x0a, 0x05, 0x04,
+    0x02, 0x02, 0x02, 0x01, 0x12, 0x03, 0x2f, 0x1c, 0x25, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02,
+    0x02, 0x03, 0x12, 0x03, 0x2f, 0x28, 0x29, 0x0a, 0x0b, 0x0a, 0x04, 0x04, 0x02, 0x02, 0x03, 0x12,
+    0x03, 0x30, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x05, 0x12, 0x03, 0x30,
+    0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x30, 0x09, 0x14,
+    0x0a, 0x0c, 0x0a, 0x05, 0x04, 0x02, 0x02, 0x03, 0x03, 0x12, 0x03, 0x30, 0x17, 0x18, 0x0a, 0xb0,
+    0x02, 0x0a, 0x02, 0x05, 0x00, 0x12, 0x04, 0x37, 0x00, 0x41, 0x01, 0x1a, 0xa3, 0x02, 0x20, 0x45,
+    0x61, 0x63, 0x68, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x20, 0x69, 0x73, 0x20, 0x67, 0x69, 0x76,
+    0x65, 0x6e, 0x20, 0x61, 0x20, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x63, 0x20, 0x69, 0x6e,
+    0x74, 0x65, 0x67, 0x65, 0x72, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x2e, 0x0a,
+    0x20, 0x54, 0x68, 0x65, 0x20, 0x68, 0x69, 0x67, 0x68, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6d, 0x6f, 0x72,
+    0x65, 0x20, 0x69, 0x6d, 0x70, 0x6f, 0x72, 0x74, 0x61, 0x6e, 0x74, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x20, 0x69, 0x73, 0x20, 0x63, 0x6f, 0x6e, 0x73, 0x69,
+    0x64, 0x65, 0x72, 0x65, 0x64, 0x20, 0x74, 0x6f, 0x20, 0x62, 0x65, 0x2c, 0x0a, 0x20, 0x61, 0x6e,
+    0x64, 0x20, 0x74, 0x68, 0x65, 0x20, 0x6c, 0x6f, 0x77, 0x65, 0x72, 0x20, 0x74, 0x68, 0x65, 0x20,
+    0x63, 0x6f, 0x72, 0x72, 0x65, 0x73, 0x70, 0x6f, 0x6e, 0x64, 0x69, 0x6e, 0x67, 0x20, 0x69, 0x6e,
+    0x74, 0x65, 0x67, 0x65, 0x72, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x79, 0x2e, 0x0a,
+    0x20, 0x46, 0x6f, 0x72, 0x20, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x2c, 0x20, 0x61, 0x73,
+    0x20, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x20, 0x65, 0x78, 0x61, 0x63, 0x74,
+    0x6c, 0x79, 0x20, 0x69, 0x6e, 0x20, 0x52, 0x46, 0x43, 0x35, 0x34, 0x32, 0x34, 0x20, 0x74, 0x68,
+    0x65, 0x20, 0x73, 0x79, 0x73, 0x6c, 0x6f, 0x67, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x20,
+    0x61, 0x72, 0x65, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x69, 0x7a, 0x65, 0x64, 0x20,
+    0x66, 0x72, 0x6f, 0x6d, 0x20, 0x30, 0x20, 0x74, 0x6f, 0x20, 0x37, 0x20, 0x28, 0x68, 0x69, 0x67,
+    0x68, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x6c, 0x6f, 0x77, 0x65, 0x73, 0x74, 0x29, 0x2e,
+    0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x00, 0x01, 0x12, 0x03, 0x37, 0x05, 0x12, 0x0a, 0x5e, 0x0a,
+    0x04, 0x05, 0x00, 0x02, 0x00, 0x12, 0x03, 0x39, 0x02, 0x0c, 0x1a, 0x30, 0x20, 0x55, 0x4e, 0x53,
+    0x50, 0x45, 0x43, 0x49, 0x46, 0x49, 0x45, 0x44, 0x20, 0x3d, 0x20, 0x30, 0x3b, 0x20, 0x2f, 0x2f,
+    0x20, 0x55, 0x6e, 0x73, 0x70, 0x65, 0x63, 0x69, 0x66, 0x69, 0x65, 0x64, 0x3a, 0x20, 0x64, 0x65,
+    0x66, 0x61, 0x75, 0x6c, 0x74, 0x20, 0x76, 0x61, 0x6c, 0x75, 0x65, 0x0a, 0x22, 0x1f, 0x20, 0x45,
+    0x6d, 0x65, 0x72, 0x67, 0x65, 0x6e, 0x63, 0x79, 0x3a, 0x20, 0x73, 0x79, 0x73, 0x74, 0x65, 0x6d,
+    0x20, 0x69, 0x73, 0x20, 0x75, 0x6e, 0x75, 0x73, 0x61, 0x62, 0x6c, 0x65, 0x0a, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x00, 0x01, 0x12, 0x03, 0x39, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x00, 0x02, 0x12, 0x03, 0x39, 0x0a, 0x0b, 0x0a, 0x36, 0x0a, 0x04, 0x05, 0x00, 0x02,
+    0x01, 0x12, 0x03, 0x3a, 0x02, 0x0c, 0x22, 0x29, 0x20, 0x41, 0x6c, 0x65, 0x72, 0x74, 0x3a, 0x20,
+    0x61, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x20, 0x6d, 0x75, 0x73, 0x74, 0x20, 0x62, 0x65, 0x20, 0x74,
+    0x61, 0x6b, 0x65, 0x6e, 0x20, 0x69, 0x6d, 0x6d, 0x65, 0x64, 0x69, 0x61, 0x74, 0x65, 0x6c, 0x79,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x01, 0x12, 0x03, 0x3a, 0x02, 0x07, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x01, 0x02, 0x12, 0x03, 0x3a, 0x0a, 0x0b, 0x0a, 0x2c, 0x0a,
+    0x04, 0x05, 0x00, 0x02, 0x02, 0x12, 0x03, 0x3b, 0x02, 0x0b, 0x22, 0x1f, 0x20, 0x43, 0x72, 0x69,
+    0x74, 0x69, 0x63, 0x61, 0x6c, 0x3a, 0x20, 0x63, 0x72, 0x69, 0x74, 0x69, 0x63, 0x61, 0x6c, 0x20,
+    0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x00, 0x02, 0x02, 0x01, 0x12, 0x03, 0x3b, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02,
+    0x02, 0x02, 0x12, 0x03, 0x3b, 0x09, 0x0a, 0x0a, 0x26, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x03, 0x12,
+    0x03, 0x3c, 0x02, 0x0c, 0x22, 0x19, 0x20, 0x45, 0x72, 0x72, 0x6f, 0x72, 0x3a, 0x20, 0x65, 0x72,
+    0x72, 0x6f, 0x72, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x0a, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x03, 0x01, 0x12, 0x03, 0x3c, 0x02, 0x07, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x00, 0x02, 0x03, 0x02, 0x12, 0x03, 0x3c, 0x0a, 0x0b, 0x0a, 0x2a, 0x0a, 0x04, 0x05,
+    0x00, 0x02, 0x04, 0x12, 0x03, 0x3d, 0x02, 0x0e, 0x22, 0x1d, 0x20, 0x57, 0x61, 0x72, 0x6e, 0x69,
+    0x6e, 0x67, 0x3a, 0x20, 0x77, 0x61, 0x72, 0x6e, 0x69, 0x6e, 0x67, 0x20, 0x63, 0x6f, 0x6e, 0x64,
+    0x69, 0x74, 0x69, 0x6f, 0x6e, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x01,
+    0x12, 0x03, 0x3d, 0x02, 0x09, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x04, 0x02, 0x12, 0x03,
+    0x3d, 0x0c, 0x0d, 0x0a, 0x37, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x05, 0x12, 0x03, 0x3e, 0x02, 0x0d,
+    0x22, 0x2a, 0x20, 0x4e, 0x6f, 0x74, 0x69, 0x63, 0x65, 0x3a, 0x20, 0x6e, 0x6f, 0x72, 0x6d, 0x61,
+    0x6c, 0x20, 0x62, 0x75, 0x74, 0x20, 0x73, 0x69, 0x67, 0x6e, 0x69, 0x66, 0x69, 0x63, 0x61, 0x6e,
+    0x74, 0x20, 0x63, 0x6f, 0x6e, 0x64, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x0a, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x05, 0x01, 0x12, 0x03, 0x3e, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00,
+    0x02, 0x05, 0x02, 0x12, 0x03, 0x3e, 0x0b, 0x0c, 0x0a, 0x34, 0x0a, 0x04, 0x05, 0x00, 0x02, 0x06,
+    0x12, 0x03, 0x3f, 0x02, 0x0b, 0x22, 0x27, 0x20, 0x49, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74,
+    0x69, 0x6f, 0x6e, 0x61, 0x6c, 0x3a, 0x20, 0x69, 0x6e, 0x66, 0x6f, 0x72, 0x6d, 0x61, 0x74, 0x69,
+    0x6f, 0x6e, 0x61, 0x6c, 0x20, 0x6d, 0x65, 0x73, 0x73, 0x61, 0x67, 0x65, 0x73, 0x0a, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x00, 0x02, 0x06, 0x01, 0x12, 0x03, 0x3f, 0x02, 0x06, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x00, 0x02, 0x06, 0x02, 0x12, 0x03, 0x3f, 0x09, 0x0a, 0x0a, 0x2a, 0x0a, 0x04, 0x05, 0x00,
+    0x02, 0x07, 0x12, 0x03, 0x40, 0x02, 0x0c, 0x22, 0x1d, 0x20, 0x44, 0x65, 0x62, 0x75, 0x67, 0x3a,
+    0x20, 0x64, 0x65, 0x62, 0x75, 0x67, 0x2d, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x20, 0x6d, 0x65, 0x73,
+    0x73, 0x61, 0x67, 0x65, 0x73, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x07, 0x01, 0x12,
+    0x03, 0x40, 0x02, 0x07, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x00, 0x02, 0x07, 0x02, 0x12, 0x03, 0x40,
+    0x0a, 0x0b, 0x0a, 0x53, 0x0a, 0x02, 0x05, 0x01, 0x12, 0x04, 0x44, 0x00, 0x48, 0x01, 0x1a, 0x47,
+    0x20, 0x57, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x20, 0x73,
+    0x65, 0x74, 0x73, 0x2c, 0x20, 0x60, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x60, 0x20, 0x61, 0x6c, 0x77,
+    0x61, 0x79, 0x73, 0x20, 0x68, 0x6f, 0x6c, 0x64, 0x73, 0x20, 0x74, 0x68, 0x65, 0x20, 0x65, 0x71,
+    0x75, 0x69, 0x76, 0x61, 0x6c, 0x65, 0x6e, 0x74, 0x20, 0x73, 0x79, 0x73, 0x6c, 0x6f, 0x67, 0x20,
+    0x6c, 0x65, 0x76, 0x65, 0x6c, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x01, 0x01, 0x12, 0x03,
+    0x44, 0x05, 0x0e, 0x0a, 0x36, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x00, 0x12, 0x03, 0x45, 0x02, 0x0d,
+    0x22, 0x29, 0x20, 0x52, 0x46, 0x43, 0x35, 0x34, 0x32, 0x34, 0x20, 0x73, 0x79, 0x73, 0x6c, 0x6f,
+    0x67, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x20, 0x28, 0x60, 0x4c, 0x6f, 0x67, 0x67, 0x69,
+    0x6e, 0x67, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x60, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x01, 0x02, 0x00, 0x01, 0x12, 0x03, 0x45, 0x02, 0x08, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02,
+    0x00, 0x02, 0x12, 0x03, 0x45, 0x0b, 0x0c, 0x0a, 0x38, 0x0a, 0x04, 0x05, 0x01, 0x02, 0x01, 0x12,
+    0x03, 0x46, 0x02, 0x0a, 0x22, 0x2b, 0x20, 0x6e, 0x70, 0x6d, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c,
+    0x73, 0x2c, 0x20, 0x77, 0x69, 0x6e, 0x73, 0x74, 0x6f, 0x6e, 0x20, 0x64, 0x65, 0x66, 0x61, 0x75,
+    0x6c, 0x74, 0x20, 0x28, 0x60, 0x4e, 0x70, 0x6d, 0x4c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x60, 0x29,
+    0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x01, 0x12, 0x03, 0x46, 0x02, 0x05, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x01, 0x02, 0x12, 0x03, 0x46, 0x08, 0x09, 0x0a, 0x27, 0x0a,
+    0x04, 0x05, 0x01, 0x02, 0x02, 0x12, 0x03, 0x47, 0x02, 0x0a, 0x22, 0x1a, 0x20, 0x63, 0x6c, 0x69,
+    0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x20, 0x28, 0x60, 0x43, 0x6c, 0x69, 0x4c, 0x65, 0x76,
+    0x65, 0x6c, 0x73, 0x60, 0x29, 0x0a, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x01, 0x12,
+    0x03, 0x47, 0x02, 0x05, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x01, 0x02, 0x02, 0x02, 0x12, 0x03, 0x47,
+    0x08, 0x09, 0x0a, 0x49, 0x0a, 0x02, 0x05, 0x02, 0x12, 0x04, 0x4b, 0x00, 0x53, 0x01, 0x1a, 0x3d,
+    0x20, 0x6e, 0x70, 0x6d, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c, 0x73, 0x20, 0x61, 0x72, 0x65, 0x20,
+    0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x69, 0x7a, 0x65, 0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d,
+    0x20, 0x30, 0x20, 0x74, 0x6f, 0x20, 0x36, 0x20, 0x28, 0x68, 0x69, 0x67, 0x68, 0x65, 0x73, 0x74,
+    0x20, 0x74, 0x6f, 0x20, 0x6c, 0x6f, 0x77, 0x65, 0x73, 0x74, 0x29, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a,
+    0x03, 0x05, 0x02, 0x01, 0x12, 0x03, 0x4b, 0x05, 0x0e, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02,
+    0x00, 0x12, 0x03, 0x4c, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x01, 0x12,
+    0x03, 0x4c, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x00, 0x02, 0x12, 0x03, 0x4c,
+    0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x01, 0x12, 0x03, 0x4d, 0x02, 0x16, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x01, 0x01, 0x12, 0x03, 0x4d, 0x02, 0x11, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x02, 0x02, 0x01, 0x02, 0x12, 0x03, 0x4d, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05,
+    0x02, 0x02, 0x02, 0x12, 0x03, 0x4e, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02,
+    0x01, 0x12, 0x03, 0x4e, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x02, 0x02, 0x12,
+    0x03, 0x4e, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x03, 0x12, 0x03, 0x4f, 0x02,
+    0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x01, 0x12, 0x03, 0x4f, 0x02, 0x11, 0x0a,
+    0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x03, 0x02, 0x12, 0x03, 0x4f, 0x14, 0x15, 0x0a, 0x0b, 0x0a,
+    0x04, 0x05, 0x02, 0x02, 0x04, 0x12, 0x03, 0x50, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
+    0x02, 0x04, 0x01, 0x12, 0x03, 0x50, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x04,
+    0x02, 0x12, 0x03, 0x50, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x05, 0x12, 0x03,
+    0x51, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x05, 0x01, 0x12, 0x03, 0x51, 0x02,
+    0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02, 0x02, 0x05, 0x02, 0x12, 0x03, 0x51, 0x15, 0x16, 0x0a,
+    0x0b, 0x0a, 0x04, 0x05, 0x02, 0x02, 0x06, 0x12, 0x03, 0x52, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x02, 0x02, 0x06, 0x01, 0x12, 0x03, 0x52, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x02,
+    0x02, 0x06, 0x02, 0x12, 0x03, 0x52, 0x15, 0x16, 0x0a, 0x49, 0x0a, 0x02, 0x05, 0x03, 0x12, 0x04,
+    0x56, 0x00, 0x61, 0x01, 0x1a, 0x3d, 0x20, 0x63, 0x6c, 0x69, 0x20, 0x6c, 0x65, 0x76, 0x65, 0x6c,
+    0x73, 0x20, 0x61, 0x72, 0x65, 0x20, 0x70, 0x72, 0x69, 0x6f, 0x72, 0x69, 0x74, 0x69, 0x7a, 0x65,
+    0x64, 0x20, 0x66, 0x72, 0x6f, 0x6d, 0x20, 0x30, 0x20, 0x74, 0x6f, 0x20, 0x39, 0x20, 0x28, 0x68,
+    0x69, 0x67, 0x68, 0x65, 0x73, 0x74, 0x20, 0x74, 0x6f, 0x20, 0x6c, 0x6f, 0x77, 0x65, 0x73, 0x74,
+    0x29, 0x2e, 0x0a, 0x0a, 0x0a, 0x0a, 0x03, 0x05, 0x03, 0x01, 0x12, 0x03, 0x56, 0x05, 0x0e, 0x0a,
+    0x0b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x00, 0x12, 0x03, 0x57, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x03, 0x02, 0x00, 0x01, 0x12, 0x03, 0x57, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03,
+    0x02, 0x00, 0x02, 0x12, 0x03, 0x57, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x01,
+    0x12, 0x03, 0x58, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x01, 0x01, 0x12, 0x03,
+    0x58, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x01, 0x02, 0x12, 0x03, 0x58, 0x14,
+    0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x02, 0x12, 0x03, 0x59, 0x02, 0x16, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x03, 0x02, 0x02, 0x01, 0x12, 0x03, 0x59, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05,
+    0x05, 0x03, 0x02, 0x02, 0x02, 0x12, 0x03, 0x59, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x03,
+    0x02, 0x03, 0x12, 0x03, 0x5a, 0x02, 0x16, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x03, 0x01,
+    0x12, 0x03, 0x5a, 0x02, 0x11, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x03, 0x02, 0x12, 0x03,
+    0x5a, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x04, 0x12, 0x03, 0x5b, 0x02, 0x16,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x04, 0x01, 0x12, 0x03, 0x5b, 0x02, 0x11, 0x0a, 0x0c,
+    0x0a, 0x05, 0x05, 0x03, 0x02, 0x04, 0x02, 0x12, 0x03, 0x5b, 0x14, 0x15, 0x0a, 0x0b, 0x0a, 0x04,
+    0x05, 0x03, 0x02, 0x05, 0x12, 0x03, 0x5c, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02,
+    0x05, 0x01, 0x12, 0x03, 0x5c, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x05, 0x02,
+    0x12, 0x03, 0x5c, 0x15, 0x16, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x06, 0x12, 0x03, 0x5d,
+    0x02, 0x18, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x06, 0x01, 0x12, 0x03, 0x5d, 0x02, 0x13,
+    0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x06, 0x02, 0x12, 0x03, 0x5d, 0x16, 0x17, 0x0a, 0x0b,
+    0x0a, 0x04, 0x05, 0x03, 0x02, 0x07, 0x12, 0x03, 0x5e, 0x02, 0x19, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x03, 0x02, 0x07, 0x01, 0x12, 0x03, 0x5e, 0x02, 0x14, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02,
+    0x07, 0x02, 0x12, 0x03, 0x5e, 0x17, 0x18, 0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x08, 0x12,
+    0x03, 0x5f, 0x02, 0x17, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x08, 0x01, 0x12, 0x03, 0x5f,
+    0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05, 0x03, 0x02, 0x08, 0x02, 0x12, 0x03, 0x5f, 0x15, 0x16,
+    0x0a, 0x0b, 0x0a, 0x04, 0x05, 0x03, 0x02, 0x09, 0x12, 0x03, 0x60, 0x02, 0x17, 0x0a, 0x0c, 0x0a,
+    0x05, 0x05, 0x03, 0x02, 0x09, 0x01, 0x12, 0x03, 0x60, 0x02, 0x12, 0x0a, 0x0c, 0x0a, 0x05, 0x05,
+    0x03, 0x02, 0x09, 0x02, 0x12, 0x03, 0x60, 0x15, 0x16, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74, 0x6f,
     0x33,
 ];
 // @@protoc_insertion_point(module)
@@ -0,0 +1,93 @@
+use crate::{helpers::to_hex, LoggerOperation};
+
+impl LoggerOperation {
+    /// Set originating transaction hash, hex encoded with `0x` prefix
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::Logger;
+    /// let logger = Logger::new("user-service");
+    /// let operation = logger.info("message").with_transaction_hash(&[0xab, 0xcd]);
+    /// assert_eq!(operation.transaction_hash.unwrap(), "0xabcd");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_transaction_hash(self, hash: &[u8]) -> Self {
+        LoggerOperation {
+            transaction_hash: Some(to_hex(hash)),
+            ..self
+        }
+    }
+
+    /// Set originating Ethereum log index or Antelope action ordinal
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::Logger;
+    /// let logger = Logger::new("user-service");
+    /// logger.info("message").with_event_index(3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_event_index(self, index: u32) -> Self {
+        LoggerOperation {
+            event_index: Some(index),
+            ..self
+        }
+    }
+
+    /// Set originating contract address, hex encoded with `0x` prefix
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::Logger;
+    /// let logger = Logger::new("user-service");
+    /// let operation = logger.info("message").with_contract(&[0xde, 0xad]);
+    /// assert_eq!(operation.contract.unwrap(), "0xdead");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_contract(self, address: &[u8]) -> Self {
+        LoggerOperation {
+            contract: Some(to_hex(address)),
+            ..self
+        }
+    }
+
+    /// Set originating contract from its name, such as an Antelope account
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::Logger;
+    /// let logger = Logger::new("user-service");
+    /// logger.info("message").with_contract_name("eosio.token");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_contract_name(self, name: &str) -> Self {
+        LoggerOperation {
+            contract: Some(name.to_string()),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Logger;
+
+    #[test]
+    fn test_provenance() {
+        let logger = Logger::new("user-service");
+        let operation = logger
+            .info("transfer")
+            .with_transaction_hash(&[0x01, 0x23, 0xab])
+            .with_event_index(7)
+            .with_contract(&[0xff; 4]);
+
+        assert_eq!(operation.transaction_hash.as_deref(), Some("0x0123ab"));
+        assert_eq!(operation.event_index, Some(7));
+        assert_eq!(operation.contract.as_deref(), Some("0xffffffff"));
+        assert_eq!(logger.info("transfer").transaction_hash, None);
+    }
+}
@@ -0,0 +1,511 @@
+use crate::{bip39, LoggerOperation, LoggerOperations};
+use prost_types::{value::Kind, Value};
+
+/// Placeholder of values under a redacted key
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Keys redacted by default, matched case-insensitively as substrings
+pub const REDACTED_KEYS: [&str; 9] = [
+    "password",
+    "secret",
+    "private_key",
+    "privatekey",
+    "priv_key",
+    "privkey",
+    "pk",
+    "mnemonic",
+    "seed",
+];
+
+/// Built-in detector of sensitive values
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Detector {
+    /// WIF keys and `PVT_` Antelope keys
+    PrivateKey,
+    /// Raw 64 hex characters keys, optionally `0x` prefixed, opt-in
+    ///
+    /// Antelope transaction and block ids, and EVM hashes, share this shape.
+    RawHexKey,
+    /// Runs of 12 or more BIP39 English words, quotes, punctuation and case ignored
+    Mnemonic,
+    /// Email addresses
+    Email,
+}
+
+impl Detector {
+    /// Detectors of `Redactor::default`, `Detector::RawHexKey` excluded
+    pub const DEFAULT: [Detector; 3] = [Detector::PrivateKey, Detector::Mnemonic, Detector::Email];
+
+    /// Masked placeholder of detected values
+    pub fn placeholder(&self) -> &'static str {
+        match self {
+            Detector::PrivateKey | Detector::RawHexKey => "[REDACTED:private_key]",
+            Detector::Mnemonic => "[REDACTED:mnemonic]",
+            Detector::Email => "[REDACTED:email]",
+        }
+    }
+
+    /// Byte ranges of the detected values in `text`
+    fn find(&self, text: &str) -> Vec<(usize, usize)> {
+        match self {
+            Detector::PrivateKey => tokens(text, |c| c.is_ascii_alphanumeric() || c == '_')
+                .filter(|&(start, end)| is_private_key(&text[start..end]))
+                .collect(),
+            Detector::RawHexKey => tokens(text, |c| c.is_ascii_alphanumeric() || c == '_')
+                .filter(|&(start, end)| is_raw_hex_key(&text[start..end]))
+                .collect(),
+            Detector::Email => tokens(text, |c| {
+                c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '%' | '+' | '-' | '@')
+            })
+            .map(|(start, end)| (start, start + text[start..end].trim_end_matches('.').len()))
+            .filter(|&(start, end)| is_email(&text[start..end]))
+            .collect(),
+            Detector::Mnemonic => mnemonics(text),
+        }
+    }
+}
+
+/// Redaction of sensitive `message` and `meta` values
+///
+/// Values under matching keys are replaced by `REDACTED`, values matched by a `Detector`
+/// are replaced by its placeholder. Defaults to `REDACTED_KEYS` and `Detector::DEFAULT`.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::Redactor;
+/// let redactor = Redactor::default();
+/// assert_eq!(redactor.redact_str("contact alice@example.com"), "contact [REDACTED:email]");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Redactor {
+    keys: Vec<String>,
+    detectors: Vec<Detector>,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Redactor {
+            keys: REDACTED_KEYS.iter().map(|k| k.to_string()).collect(),
+            detectors: Detector::DEFAULT.to_vec(),
+        }
+    }
+}
+
+impl Redactor {
+    /// Redactor without keys nor detectors
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Redactor {
+            keys: vec![],
+            detectors: vec![],
+        }
+    }
+
+    /// Redact values under keys containing `pattern`, case-insensitively
+    #[inline]
+    #[must_use]
+    pub fn with_key(mut self, pattern: &str) -> Self {
+        self.keys.push(pattern.to_lowercase());
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn with_detector(mut self, detector: Detector) -> Self {
+        if !self.detectors.contains(&detector) {
+            self.detectors.push(detector);
+        }
+        self
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn without_detector(mut self, detector: Detector) -> Self {
+        self.detectors.retain(|d| *d != detector);
+        self
+    }
+
+    pub fn is_redacted_key(&self, k: &str) -> bool {
+        let k = k.to_lowercase();
+        self.keys.iter().any(|pattern| k.contains(pattern.as_str()))
+    }
+
+    /// Replace detected values in `text` with their placeholders
+    #[must_use]
+    pub fn redact_str(&self, text: &str) -> String {
+        let mut text = text.to_string();
+        for detector in &self.detectors {
+            for (start, end) in detector.find(&text).into_iter().rev() {
+                text.replace_range(start..end, detector.placeholder());
+            }
+        }
+        text
+    }
+
+    fn redact_value(&self, value: &mut Value) {
+        match &mut value.kind {
+            Some(Kind::StringValue(s)) => *s = self.redact_str(s),
+            Some(Kind::ListValue(list)) => {
+                for value in &mut list.values {
+                    self.redact_value(value);
+                }
+            }
+            Some(Kind::StructValue(object)) => {
+                for (k, value) in &mut object.fields {
+                    match self.is_redacted_key(k) {
+                        true => *value = redacted(),
+                        false => self.redact_value(value),
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn redacted() -> Value {
+    Value {
+        kind: Some(Kind::StringValue(REDACTED.to_string())),
+    }
+}
+
+/// Byte ranges of the maximal runs of `token` characters
+fn tokens(text: &str, token: impl Fn(char) -> bool) -> impl Iterator<Item = (usize, usize)> {
+    let mut spans = vec![];
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        match (token(c), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                spans.push((s, i));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    if let Some(s) = start {
+        spans.push((s, text.len()));
+    }
+    spans.into_iter()
+}
+
+fn is_private_key(token: &str) -> bool {
+    const BASE58: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+    let base58 = |s: &str| s.chars().all(|c| BASE58.contains(c));
+    if let Some(key) = token.strip_prefix("PVT_") {
+        return key.len() > 40 && matches!(key.rsplit('_').next(), Some(k) if base58(k));
+    }
+    match token.chars().next() {
+        Some('5') => token.len() == 51 && base58(token),
+        Some('K' | 'L') => token.len() == 52 && base58(token),
+        _ => false,
+    }
+}
+
+fn is_raw_hex_key(token: &str) -> bool {
+    let token = token.strip_prefix("0x").unwrap_or(token);
+    token.len() == 64 && token.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn is_email(token: &str) -> bool {
+    let (local, domain) = match token.split_once('@') {
+        Some(parts) => parts,
+        None => return false,
+    };
+    let labels: Vec<&str> = domain.split('.').collect();
+    !local.is_empty()
+        && !local.starts_with('.')
+        && labels.len() >= 2
+        && labels.iter().all(|label| {
+            !label.is_empty() && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+        && matches!(labels.last(), Some(tld) if tld.len() >= 2 && tld.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
+/// BIP39 phrases found in runs of BIP39 words
+///
+/// Whitespace separated tokens are stripped of surrounding quotes and punctuation,
+/// punctuation other than commas ending the run. Within a run of 12 or more words, only
+/// the phrases with a valid checksum are masked, the whole run when none is valid.
+fn mnemonics(text: &str) -> Vec<(usize, usize)> {
+    const MIN_WORDS: usize = 12;
+    let mut spans = vec![];
+    let mut run: Vec<(usize, usize)> = vec![];
+    let mut flush = |run: &mut Vec<(usize, usize)>| {
+        if run.len() >= MIN_WORDS {
+            spans.extend(phrases(text, run));
+        }
+        run.clear();
+    };
+    let is_break = |s: &str| s.chars().any(|c| c != ',');
+    for (start, end) in tokens(text, |c| !c.is_whitespace()) {
+        let token = &text[start..end];
+        let word = token.trim_start_matches(|c: char| !c.is_alphanumeric());
+        let leading = &token[..token.len() - word.len()];
+        let word = word.trim_end_matches(|c: char| !c.is_alphanumeric());
+        let trailing = &token[leading.len() + word.len()..];
+        if bip39::english_index(word).is_none() {
+            flush(&mut run);
+            continue;
+        }
+        if is_break(leading) {
+            flush(&mut run);
+        }
+        let start = start + leading.len();
+        run.push((start, start + word.len()));
+        if is_break(trailing) {
+            flush(&mut run);
+        }
+    }
+    flush(&mut run);
+    spans
+}
+
+/// Spans of the checksum-valid phrases of a run, longest first, else the whole run
+fn phrases(text: &str, run: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let words: Vec<&str> = run.iter().map(|&(start, end)| &text[start..end]).collect();
+    let mut spans = vec![];
+    let mut i = 0;
+    while i < words.len() {
+        let len = [24, 21, 18, 15, 12]
+            .into_iter()
+            .find(|&len| i + len <= words.len() && bip39::is_valid_phrase(&words[i..i + len]));
+        match len {
+            Some(len) => {
+                spans.push((run[i].0, run[i + len - 1].1));
+                i += len;
+            }
+            None => i += 1,
+        }
+    }
+    if spans.is_empty() {
+        spans.push((run[0].0, run[run.len() - 1].1));
+    }
+    spans
+}
+
+impl LoggerOperation {
+    /// Redact sensitive `message` and `meta` values
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, Meta, Redactor};
+    /// let logger = Logger::new("user-service");
+    /// let mut operation = logger.info("memo").with(Meta::from(vec![("password", "hunter2")]));
+    /// operation.redact(&Redactor::default());
+    /// ```
+    pub fn redact(&mut self, redactor: &Redactor) {
+        self.message = redactor.redact_str(&self.message);
+        if let Some(template) = &mut self.message_template {
+            *template = redactor.redact_str(template);
+        }
+        if let Some(meta) = &mut self.meta {
+            for (k, value) in &mut meta.fields {
+                match redactor.is_redacted_key(k) {
+                    true => *value = redacted(),
+                    false => redactor.redact_value(value),
+                }
+            }
+        }
+        for (k, value) in &mut self.legacy_meta {
+            *value = match redactor.is_redacted_key(k) {
+                true => REDACTED.to_string(),
+                false => redactor.redact_str(value),
+            };
+        }
+    }
+}
+
+impl LoggerOperations {
+    /// Redact sensitive values of every operation, see `LoggerOperation::redact`
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggerOperations, Redactor};
+    /// let mut log_ops: LoggerOperations = Default::default();
+    /// let logger = Logger::new("user-service");
+    /// logger.push(&mut log_ops, logger.info("sent by bob@example.com"));
+    /// log_ops.redact(&Redactor::default());
+    /// assert_eq!(log_ops.operations[0].message, "sent by [REDACTED:email]");
+    /// ```
+    pub fn redact(&mut self, redactor: &Redactor) {
+        for operation in &mut self.operations {
+            operation.redact(redactor);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntoValue, Logger, Meta};
+
+    const HEX_KEY: &str = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+    const WIF_KEY: &str = "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ";
+    const MNEMONIC: &str =
+        "legal winner thank year wave sausage worth useful legal winner thank yellow";
+
+    #[test]
+    fn test_detect_private_key() {
+        let redactor = Redactor::new().with_detector(Detector::PrivateKey);
+
+        assert_eq!(
+            redactor.redact_str(&format!("wif {}", WIF_KEY)),
+            "wif [REDACTED:private_key]"
+        );
+        assert_eq!(
+            redactor.redact_str("PVT_K1_2bfGi9rYsXQSXXTvJbDAPhHLQUojjaNLomdm3cEJ1XTzMqUt3V"),
+            "[REDACTED:private_key]"
+        );
+        let key = format!("key={}", HEX_KEY);
+        assert_eq!(redactor.redact_str(&key), key);
+        assert_eq!(redactor.redact_str("deadbeef"), "deadbeef");
+    }
+
+    #[test]
+    fn test_detect_raw_hex_key() {
+        let redactor = Redactor::new().with_detector(Detector::RawHexKey);
+
+        assert_eq!(
+            redactor.redact_str(&format!("key={}", HEX_KEY)),
+            "key=[REDACTED:private_key]"
+        );
+        assert_eq!(
+            redactor.redact_str(&format!("key=0x{}", HEX_KEY)),
+            "key=[REDACTED:private_key]"
+        );
+        let short = format!("0x{}", &HEX_KEY[..40]);
+        assert_eq!(redactor.redact_str(&short), short);
+    }
+
+    #[test]
+    fn test_redact_evm_private_key() {
+        let logger = Logger::new("user-service");
+        let key = format!("0x{}", HEX_KEY);
+        for name in ["privKey", "priv_key", "pk", "privateKey"] {
+            let mut operation = logger.info("message").with_field(name, key.as_str());
+            operation.redact(&Redactor::default());
+
+            assert_eq!(
+                Meta::from(&operation).get(name),
+                Some(&REDACTED.into_value()),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_antelope_ids_unchanged() {
+        let trx_id = "trx 8f8b3a1d6dc4f0b6b5e5d1a4c9f2e3b7a6d5c4b3a2f1e0d9c8b7a6f5e4d3c2b1";
+        let block_id = "block 0c4a7f5e8d2b1a3c6e9f0d7b5a4c3e2f1d0b9a8c7e6f5d4c3b2a1f0e9d8c7b6a";
+
+        assert_eq!(Redactor::default().redact_str(trx_id), trx_id);
+        assert_eq!(Redactor::default().redact_str(block_id), block_id);
+    }
+
+    #[test]
+    fn test_detect_mnemonic() {
+        let redactor = Redactor::new().with_detector(Detector::Mnemonic);
+
+        assert_eq!(
+            redactor.redact_str(&format!("seed: {} end", MNEMONIC)),
+            "seed: [REDACTED:mnemonic] end"
+        );
+        let sentence = "the pool was drained by a flash loan during the last block of the day";
+        assert_eq!(redactor.redact_str(sentence), sentence);
+        let prose = "the quick brown fox jumps over the lazy dog and then runs away from home";
+        assert_eq!(redactor.redact_str(prose), prose);
+        let invalid =
+            "abandon ability able about above absent absorb abstract absurd abuse access accident";
+        assert_eq!(redactor.redact_str(invalid), "[REDACTED:mnemonic]");
+    }
+
+    #[test]
+    fn test_detect_mnemonic_punctuated() {
+        let redactor = Redactor::new().with_detector(Detector::Mnemonic);
+
+        assert_eq!(
+            redactor.redact_str(&format!("seed \"{}\" end", MNEMONIC)),
+            "seed \"[REDACTED:mnemonic]\" end"
+        );
+        assert_eq!(
+            redactor.redact_str(&format!("my phrase is {}. Keep it safe", MNEMONIC)),
+            "my phrase is [REDACTED:mnemonic]. Keep it safe"
+        );
+        assert_eq!(
+            redactor.redact_str(&format!("'{}'", MNEMONIC.to_uppercase())),
+            "'[REDACTED:mnemonic]'"
+        );
+        assert_eq!(
+            redactor.redact_str(&MNEMONIC.replace(' ', ", ")),
+            "[REDACTED:mnemonic]"
+        );
+    }
+
+    #[test]
+    fn test_detect_email() {
+        let redactor = Redactor::new().with_detector(Detector::Email);
+
+        assert_eq!(
+            redactor.redact_str("mail john.doe+dex@mail.example.com."),
+            "mail [REDACTED:email]."
+        );
+        assert_eq!(
+            redactor.redact_str("@pinax, a@b, x@y.1"),
+            "@pinax, a@b, x@y.1"
+        );
+    }
+
+    #[test]
+    fn test_redact_keys() {
+        let redactor = Redactor::new().with_key("token");
+
+        assert!(redactor.is_redacted_key("API_TOKEN"));
+        assert!(!redactor.is_redacted_key("password"));
+        assert!(Redactor::default().is_redacted_key("userPassword"));
+        assert!(Redactor::default().is_redacted_key("signer_privKey"));
+    }
+
+    #[test]
+    fn test_redact_operation() {
+        let logger = Logger::new("user-service");
+        let mut nested = Meta::new();
+        nested.insert("secret", "abc");
+        nested.insert("memo", format!("pk {}", WIF_KEY));
+
+        let mut meta = Meta::new();
+        meta.insert("password", "hunter2");
+        meta.insert("user", nested);
+        meta.insert("memos", vec!["ok", "me@example.org"]);
+        meta.insert("block", 1);
+
+        let mut operation = logger.info(&format!("memo: {}", MNEMONIC)).with(meta);
+        operation
+            .legacy_meta
+            .insert("seed_phrase".to_string(), MNEMONIC.to_string());
+        operation.redact(&Redactor::default());
+
+        let meta = Meta::from(&operation);
+        let mut nested = Meta::new();
+        nested.insert("memo", "pk [REDACTED:private_key]");
+        nested.insert("secret", REDACTED);
+
+        assert_eq!(operation.message, "memo: [REDACTED:mnemonic]");
+        assert_eq!(meta.get("password"), Some(&REDACTED.into_value()));
+        assert_eq!(meta.get("user"), Some(&nested.into_value()));
+        assert_eq!(
+            meta.get("memos"),
+            Some(&vec!["ok", "[REDACTED:email]"].into_value())
+        );
+        assert_eq!(meta.get("block"), Some(&1.into_value()));
+        assert_eq!(operation.legacy_meta["seed_phrase"], REDACTED);
+    }
+
+    #[test]
+    fn test_without_detector() {
+        let redactor = Redactor::default().without_detector(Detector::Email);
+        assert_eq!(redactor.redact_str("me@example.org"), "me@example.org");
+    }
+}
@@ -0,0 +1,220 @@
+use crate::{
+    helpers::{format_decimals, to_hex},
+    Meta,
+};
+use std::fmt::Display;
+
+/// Insertion helpers for Substreams scalars
+///
+/// Big numbers are inserted through their `Display` implementation, as strings, keeping
+/// the precision a `f64` meta number would lose. With the `substreams` feature,
+/// `substreams::scalar::BigInt` and `BigDecimal` are also accepted directly,
+/// see `Meta::insert_big_int_amount`.
+/// Addresses and hashes are any bytes, such as `Vec<u8>` or `[u8; 20]`.
+impl Meta {
+    /// Insert bytes, such as an address or a hash, as a `0x` prefixed hex string
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{IntoValue, Meta};
+    /// let mut meta = Meta::new();
+    /// meta.insert_hex("address", [0xde, 0xad]);
+    /// assert_eq!(meta.get("address"), Some(&"0xdead".into_value()));
+    /// ```
+    pub fn insert_hex<B: AsRef<[u8]>>(&mut self, k: &str, bytes: B) {
+        self.insert(k, to_hex(bytes.as_ref()));
+    }
+
+    /// Insert a big number, such as a `BigInt` or `BigDecimal`, as a string
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{IntoValue, Meta};
+    /// let mut meta = Meta::new();
+    /// meta.insert_big("supply", u128::MAX);
+    /// assert_eq!(
+    ///     meta.get("supply"),
+    ///     Some(&"340282366920938463463374607431768211455".into_value())
+    /// );
+    /// ```
+    pub fn insert_big<N: Display>(&mut self, k: &str, number: N) {
+        self.insert(k, number.to_string());
+    }
+
+    /// Insert a raw token amount, such as a `BigInt`, shifted by the token `decimals`
+    ///
+    /// Amounts not matching `-?digits(.digits)?`, such as `NaN`, or needing more than 1024
+    /// decimals are inserted as-is.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{IntoValue, Meta};
+    /// let mut meta = Meta::new();
+    /// meta.insert_amount("amount", 1_500_000_000_000_000_000u128, 18);
+    /// assert_eq!(meta.get("amount"), Some(&"1.5".into_value()));
+    /// ```
+    pub fn insert_amount<N: Display>(&mut self, k: &str, amount: N, decimals: u32) {
+        self.insert(k, format_amount(amount, decimals));
+    }
+}
+
+/// Render a raw token amount as a decimal string, shifted by the token `decimals`
+///
+/// Amounts not matching `-?digits(.digits)?`, or needing more than 1024 decimals,
+/// are returned unchanged.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::format_amount;
+/// assert_eq!(format_amount(-12345, 4), "-1.2345");
+/// assert_eq!(format_amount("1000000", 6), "1");
+/// assert_eq!(format_amount(f64::NAN, 2), "NaN");
+/// ```
+#[must_use]
+pub fn format_amount<N: Display>(amount: N, decimals: u32) -> String {
+    let amount = amount.to_string();
+    format_decimals(&amount, decimals).unwrap_or(amount)
+}
+
+#[cfg(feature = "substreams")]
+mod big {
+    use crate::{
+        helpers::{format_decimals, MAX_DECIMALS},
+        IntoValue, Meta,
+    };
+    use prost_types::Value;
+    use substreams::scalar::{BigDecimal, BigInt};
+
+    impl IntoValue for BigInt {
+        #[inline]
+        fn into_value(self) -> Value {
+            self.to_string().into_value()
+        }
+    }
+
+    impl IntoValue for &BigInt {
+        #[inline]
+        fn into_value(self) -> Value {
+            self.to_string().into_value()
+        }
+    }
+
+    /// Plain decimal notation, trailing fractional zeros trimmed
+    ///
+    /// Values needing more than `MAX_DECIMALS` padding zeros use exponent notation (ex: `1e2000`).
+    impl IntoValue for BigDecimal {
+        #[inline]
+        fn into_value(self) -> Value {
+            (&self).into_value()
+        }
+    }
+
+    impl IntoValue for &BigDecimal {
+        fn into_value(self) -> Value {
+            let (digits, scale) = self.as_bigint_and_exponent();
+            let digits = digits.to_string();
+            let plain = match u32::try_from(scale) {
+                Ok(scale) => format_decimals(&digits, scale),
+                Err(_) if scale.unsigned_abs() <= MAX_DECIMALS as u64 => {
+                    let zeros = "0".repeat(scale.unsigned_abs() as usize);
+                    format_decimals(&format!("{}{}", digits, zeros), 0)
+                }
+                Err(_) => None,
+            };
+            plain
+                .unwrap_or_else(|| format!("{}e{}", digits, -scale))
+                .into_value()
+        }
+    }
+
+    impl Meta {
+        /// Insert a raw token amount held in a `BigInt`, shifted by the token `decimals`
+        ///
+        /// ### Example
+        /// ```
+        /// use substreams::scalar::BigInt;
+        /// use substreams_sink_winston::{IntoValue, Meta};
+        /// let mut meta = Meta::new();
+        /// let amount: BigInt = "1500000000000000000".parse().unwrap();
+        /// meta.insert_big_int_amount("amount", &amount, 18);
+        /// assert_eq!(meta.get("amount"), Some(&"1.5".into_value()));
+        /// ```
+        pub fn insert_big_int_amount(&mut self, k: &str, amount: &BigInt, decimals: u32) {
+            let amount = amount.to_string();
+            let amount = format_decimals(&amount, decimals).unwrap_or(amount);
+            self.insert(k, amount);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{IntoValue, Meta};
+
+    #[test]
+    fn test_insert_scalars() {
+        let mut meta = Meta::new();
+        meta.insert_hex("address", vec![0x00, 0x11, 0xff]);
+        meta.insert_hex("empty", b"");
+        meta.insert_big("big", "123456789012345678901234567890");
+        meta.insert_big("decimal", 1.25);
+        meta.insert_amount("amount", "-1000000000000000001", 18);
+
+        assert_eq!(meta.get("address"), Some(&"0x0011ff".into_value()));
+        assert_eq!(meta.get("empty"), Some(&"0x".into_value()));
+        assert_eq!(
+            meta.get("big"),
+            Some(&"123456789012345678901234567890".into_value())
+        );
+        assert_eq!(meta.get("decimal"), Some(&"1.25".into_value()));
+        assert_eq!(
+            meta.get("amount"),
+            Some(&"-1.000000000000000001".into_value())
+        );
+    }
+
+    #[test]
+    fn test_insert_amount_invalid() {
+        let mut meta = Meta::new();
+        meta.insert_amount("nan", f64::NAN, 2);
+        meta.insert_amount("accent", "é", 1);
+        meta.insert_amount("dotted", "1.2.3", 1);
+        meta.insert_amount("wide", 1, u32::MAX);
+
+        assert_eq!(meta.get("nan"), Some(&"NaN".into_value()));
+        assert_eq!(meta.get("accent"), Some(&"é".into_value()));
+        assert_eq!(meta.get("dotted"), Some(&"1.2.3".into_value()));
+        assert_eq!(meta.get("wide"), Some(&"1".into_value()));
+    }
+
+    #[cfg(feature = "substreams")]
+    #[test]
+    fn test_insert_substreams_scalars() {
+        use substreams::scalar::{BigDecimal, BigInt};
+
+        let big: BigInt = "-123456789012345678901234567890".parse().unwrap();
+        let mut meta = Meta::new();
+        meta.insert("big", &big);
+        meta.insert("price", "1.250".parse::<BigDecimal>().unwrap());
+        meta.insert("large", "1e30".parse::<BigDecimal>().unwrap());
+        meta.insert("huge", "1e999999999".parse::<BigDecimal>().unwrap());
+        meta.insert("tiny", "1e-999999999".parse::<BigDecimal>().unwrap());
+        meta.insert_big_int_amount("amount", &big, 18);
+
+        assert_eq!(
+            meta.get("big"),
+            Some(&"-123456789012345678901234567890".into_value())
+        );
+        assert_eq!(meta.get("price"), Some(&"1.25".into_value()));
+        assert_eq!(
+            meta.get("large"),
+            Some(&"1000000000000000000000000000000".into_value())
+        );
+        assert_eq!(
+            meta.get("amount"),
+            Some(&"-123456789012.34567890123456789".into_value())
+        );
+        assert_eq!(meta.get("huge"), Some(&"1e999999999".into_value()));
+        assert_eq!(meta.get("tiny"), Some(&"1e-999999999".into_value()));
+    }
+}
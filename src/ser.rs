@@ -0,0 +1,580 @@
+use crate::{IntoValue, Meta};
+use prost_types::{value::Kind, Struct, Value};
+use serde::ser::{self, Serialize};
+use std::fmt;
+
+/// Layout of nested structures when converting a `Serialize` value into `Meta`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Flatten {
+    /// Nested structures are kept as nested object values
+    #[default]
+    Nested,
+    /// Nested structures are flattened into dotted key paths (ex: `pool.token0`)
+    Dotted,
+}
+
+/// Error returned when a `Serialize` value can not be converted into `Meta`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SerializeError(pub String);
+
+impl fmt::Display for SerializeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsupported meta value: {}", self.0)
+    }
+}
+
+impl std::error::Error for SerializeError {}
+
+impl ser::Error for SerializeError {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        SerializeError(msg.to_string())
+    }
+}
+
+impl Meta {
+    /// Build `Meta` from any `Serialize` struct or map, keeping nested values
+    ///
+    /// Requires the `serde` feature.
+    ///
+    /// ### Example
+    /// ```
+    /// use serde::Serialize;
+    /// use substreams_sink_winston::Meta;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Swap { pool: String, amount: f64 }
+    ///
+    /// let meta = Meta::from_serialize(&Swap { pool: "abc".to_string(), amount: 1.5 }).unwrap();
+    /// assert_eq!(meta.len(), 2);
+    /// ```
+    pub fn from_serialize<T: Serialize + ?Sized>(value: &T) -> Result<Meta, SerializeError> {
+        Meta::from_serialize_with(value, Flatten::Nested)
+    }
+
+    /// Build `Meta` from any `Serialize` struct or map, with the given layout of nested structures
+    ///
+    /// ### Example
+    /// ```
+    /// use serde::Serialize;
+    /// use substreams_sink_winston::{Flatten, Meta};
+    ///
+    /// #[derive(Serialize)]
+    /// struct Token { symbol: String }
+    /// #[derive(Serialize)]
+    /// struct Swap { token: Token }
+    ///
+    /// let swap = Swap { token: Token { symbol: "EOS".to_string() } };
+    /// let meta = Meta::from_serialize_with(&swap, Flatten::Dotted).unwrap();
+    /// assert!(meta.get("token.symbol").is_some());
+    /// ```
+    pub fn from_serialize_with<T: Serialize + ?Sized>(
+        value: &T,
+        flatten: Flatten,
+    ) -> Result<Meta, SerializeError> {
+        let fields = match value.serialize(ValueSerializer)? {
+            Node::Object(fields) => fields,
+            _ => {
+                return Err(SerializeError(
+                    "expected a struct or map at the top level".to_string(),
+                ))
+            }
+        };
+        let mut meta = Meta::new();
+        for (k, node) in fields {
+            match flatten {
+                Flatten::Nested => meta.insert(&k, node),
+                Flatten::Dotted => insert_dotted(&mut meta, k, node),
+            }
+        }
+        Ok(meta)
+    }
+}
+
+fn insert_dotted(meta: &mut Meta, key: String, node: Node) {
+    match node {
+        Node::Object(fields) if !fields.is_empty() => {
+            for (k, node) in fields {
+                insert_dotted(meta, format!("{}.{}", key, k), node);
+            }
+        }
+        node => meta.insert(&key, node),
+    }
+}
+
+/// Serialized value, objects keeping their fields in serialization order
+enum Node {
+    Value(Value),
+    List(Vec<Node>),
+    Object(Vec<(String, Node)>),
+}
+
+/// Nested objects are `google.protobuf.Struct` values, sorted by key
+impl IntoValue for Node {
+    fn into_value(self) -> Value {
+        match self {
+            Node::Value(value) => value,
+            Node::List(nodes) => nodes.into_value(),
+            Node::Object(fields) => Struct {
+                fields: fields
+                    .into_iter()
+                    .map(|(k, node)| (k, node.into_value()))
+                    .collect(),
+            }
+            .into_value(),
+        }
+    }
+}
+
+/// Serializer into a `google.protobuf.Value`
+///
+/// Top level fields keep their declaration order in `Meta`, 64 and 128-bit integers
+/// are serialized as strings, see `IntoValue`, and bytes are `0x` hex encoded.
+struct ValueSerializer;
+
+fn value<V: IntoValue>(v: V) -> Result<Node, SerializeError> {
+    Ok(Node::Value(v.into_value()))
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Node;
+    type Error = SerializeError;
+    type SerializeSeq = SerializeList;
+    type SerializeTuple = SerializeList;
+    type SerializeTupleStruct = SerializeList;
+    type SerializeTupleVariant = SerializeList;
+    type SerializeMap = SerializeObject;
+    type SerializeStruct = SerializeObject;
+    type SerializeStructVariant = SerializeObject;
+
+    fn serialize_bool(self, v: bool) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_i8(self, v: i8) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_i16(self, v: i16) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_i32(self, v: i32) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_i64(self, v: i64) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_i128(self, v: i128) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_u8(self, v: u8) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_u16(self, v: u16) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_u32(self, v: u32) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_u64(self, v: u64) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_u128(self, v: u128) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_f32(self, v: f32) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_f64(self, v: f64) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_char(self, v: char) -> Result<Node, SerializeError> {
+        value(v.to_string())
+    }
+    fn serialize_str(self, v: &str) -> Result<Node, SerializeError> {
+        value(v)
+    }
+    fn serialize_bytes(self, v: &[u8]) -> Result<Node, SerializeError> {
+        value(crate::helpers::to_hex(v))
+    }
+    fn serialize_none(self) -> Result<Node, SerializeError> {
+        value(crate::null_value())
+    }
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Node, SerializeError> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<Node, SerializeError> {
+        value(crate::null_value())
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Node, SerializeError> {
+        value(crate::null_value())
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+    ) -> Result<Node, SerializeError> {
+        value(variant)
+    }
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Node, SerializeError> {
+        value.serialize(self)
+    }
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Node, SerializeError> {
+        Ok(Node::Object(vec![(
+            variant.to_string(),
+            value.serialize(self)?,
+        )]))
+    }
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeList, SerializeError> {
+        Ok(SerializeList {
+            variant: None,
+            values: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+    fn serialize_tuple(self, len: usize) -> Result<SerializeList, SerializeError> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeList, SerializeError> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeList, SerializeError> {
+        Ok(SerializeList {
+            variant: Some(variant),
+            values: Vec::with_capacity(len),
+        })
+    }
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeObject, SerializeError> {
+        Ok(SerializeObject {
+            variant: None,
+            key: None,
+            fields: Vec::with_capacity(len.unwrap_or_default()),
+        })
+    }
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeObject, SerializeError> {
+        self.serialize_map(Some(len))
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeObject, SerializeError> {
+        Ok(SerializeObject {
+            variant: Some(variant),
+            key: None,
+            fields: Vec::with_capacity(len),
+        })
+    }
+}
+
+struct SerializeList {
+    variant: Option<&'static str>,
+    values: Vec<Node>,
+}
+
+impl SerializeList {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.values.push(value.serialize(ValueSerializer)?);
+        Ok(())
+    }
+    fn finish(self) -> Result<Node, SerializeError> {
+        let list = Node::List(self.values);
+        Ok(match self.variant {
+            Some(variant) => Node::Object(vec![(variant.to_string(), list)]),
+            None => list,
+        })
+    }
+}
+
+impl ser::SerializeSeq for SerializeList {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Node, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SerializeList {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(
+        &mut self,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Node, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeList {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Node, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeList {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Node, SerializeError> {
+        self.finish()
+    }
+}
+
+struct SerializeObject {
+    variant: Option<&'static str>,
+    key: Option<String>,
+    fields: Vec<(String, Node)>,
+}
+
+impl SerializeObject {
+    fn finish(self) -> Result<Node, SerializeError> {
+        let value = Node::Object(self.fields);
+        Ok(match self.variant {
+            Some(variant) => Node::Object(vec![(variant.to_string(), value)]),
+            None => value,
+        })
+    }
+}
+
+impl ser::SerializeMap for SerializeObject {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerializeError> {
+        let key = match key.serialize(ValueSerializer)?.into_value().kind {
+            Some(Kind::StringValue(key)) => key,
+            Some(Kind::NumberValue(n)) => n.to_string(),
+            Some(Kind::BoolValue(b)) => b.to_string(),
+            _ => {
+                return Err(SerializeError(
+                    "map keys must be strings or numbers".to_string(),
+                ))
+            }
+        };
+        self.key = Some(key);
+        Ok(())
+    }
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerializeError> {
+        let key = self
+            .key
+            .take()
+            .ok_or_else(|| SerializeError("map value without key".to_string()))?;
+        self.fields.push((key, value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+    fn end(self) -> Result<Node, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for SerializeObject {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.fields
+            .push((key.to_string(), value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+    fn end(self) -> Result<Node, SerializeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeObject {
+    type Ok = Node;
+    type Error = SerializeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerializeError> {
+        self.fields
+            .push((key.to_string(), value.serialize(ValueSerializer)?));
+        Ok(())
+    }
+    fn end(self) -> Result<Node, SerializeError> {
+        self.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+    use std::collections::HashMap;
+
+    #[derive(Serialize)]
+    struct Token {
+        symbol: String,
+        decimals: u8,
+    }
+
+    #[derive(Serialize)]
+    #[allow(dead_code)]
+    enum Side {
+        Buy,
+        Sell { price: f64 },
+    }
+
+    #[derive(Serialize)]
+    struct Swap {
+        pool: String,
+        amount: u128,
+        token: Token,
+        side: Side,
+        route: Vec<&'static str>,
+        memo: Option<String>,
+        #[serde(with = "serde_bytes_hex")]
+        tx: Vec<u8>,
+    }
+
+    mod serde_bytes_hex {
+        pub fn serialize<S: serde::Serializer>(bytes: &[u8], s: S) -> Result<S::Ok, S::Error> {
+            s.serialize_bytes(bytes)
+        }
+    }
+
+    fn swap() -> Swap {
+        Swap {
+            pool: "abc".to_string(),
+            amount: 340282366920938463463374607431768211455,
+            token: Token {
+                symbol: "EOS".to_string(),
+                decimals: 4,
+            },
+            side: Side::Sell { price: 1.5 },
+            route: vec!["a", "b"],
+            memo: None,
+            tx: vec![0xab, 0xcd],
+        }
+    }
+
+    #[test]
+    fn test_from_serialize_nested() {
+        let meta = Meta::from_serialize(&swap()).unwrap();
+        let mut token = Meta::new();
+        token.insert("decimals", 4);
+        token.insert("symbol", "EOS");
+
+        assert_eq!(
+            meta.keys().collect::<Vec<_>>(),
+            vec!["pool", "amount", "token", "side", "route", "memo", "tx"]
+        );
+        assert_eq!(
+            meta.get("amount"),
+            Some(&"340282366920938463463374607431768211455".into_value())
+        );
+        assert_eq!(meta.get("token"), Some(&token.into_value()));
+        assert_eq!(meta.get("route"), Some(&vec!["a", "b"].into_value()));
+        assert_eq!(meta.get("memo"), Some(&None::<u8>.into_value()));
+        assert_eq!(meta.get("tx"), Some(&"0xabcd".into_value()));
+    }
+
+    #[test]
+    fn test_from_serialize_dotted() {
+        let meta = Meta::from_serialize_with(&swap(), Flatten::Dotted).unwrap();
+
+        assert_eq!(
+            meta.keys().collect::<Vec<_>>(),
+            vec![
+                "pool",
+                "amount",
+                "token.symbol",
+                "token.decimals",
+                "side.Sell.price",
+                "route",
+                "memo",
+                "tx"
+            ]
+        );
+
+        assert_eq!(meta.get("token.symbol"), Some(&"EOS".into_value()));
+        assert_eq!(meta.get("side.Sell.price"), Some(&1.5.into_value()));
+        assert!(meta.get("token").is_none());
+    }
+
+    #[test]
+    fn test_from_serialize_wide_integers() {
+        let map = HashMap::from([("small", 1u64), ("large", (1u64 << 53) + 1)]);
+        let meta = Meta::from_serialize(&map).unwrap();
+
+        assert_eq!(meta.get("small"), Some(&"1".into_value()));
+        assert_eq!(meta.get("large"), Some(&"9007199254740993".into_value()));
+
+        let map = HashMap::from([("min", i64::MIN), ("small", -42)]);
+        let meta = Meta::from_serialize(&map).unwrap();
+        assert_eq!(meta.get("min"), Some(&"-9223372036854775808".into_value()));
+        assert_eq!(meta.get("small"), Some(&"-42".into_value()));
+    }
+
+    #[test]
+    fn test_from_serialize_map() {
+        let map = HashMap::from([(1, "one")]);
+        let meta = Meta::from_serialize(&map).unwrap();
+
+        assert_eq!(meta.get("1"), Some(&"one".into_value()));
+    }
+
+    #[test]
+    fn test_from_serialize_errors() {
+        assert!(Meta::from_serialize(&vec![1, 2]).is_err());
+        assert!(Meta::from_serialize(&"value").is_err());
+
+        let map = HashMap::from([((1, 2), "tuple key")]);
+        assert_eq!(
+            Meta::from_serialize(&map),
+            Err(SerializeError(
+                "map keys must be strings or numbers".to_string()
+            ))
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{value_to_string, Level, Logger, LoggerOperation, Meta};
+
+/// Render a message template with its arguments
+///
+/// Placeholders are `{name}` meta keys, optionally followed by a `:format` which is ignored.
+/// `{{` and `}}` render as literal braces, placeholders without a matching argument
+/// and malformed placeholders are rendered as-is.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{render_template, Meta};
+/// let args = Meta::from(vec!(["amount", "1.5"], ["pool", "abc"]));
+/// let message = render_template("Swap of {amount} on {pool} {{escaped}}", &args);
+/// assert_eq!(message, "Swap of 1.5 on abc {escaped}");
+/// ```
+#[must_use]
+pub fn render_template(template: &str, args: &Meta) -> String {
+    let mut message = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(index) = rest.find(['{', '}']) {
+        message.push_str(&rest[..index]);
+        let tail = &rest[index..];
+
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            message.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        if let Some(after) = tail.strip_prefix('}') {
+            message.push('}');
+            rest = after;
+            continue;
+        }
+        match parse_placeholder(tail) {
+            Some((name, len)) => {
+                match args.get(name) {
+                    Some(value) => message.push_str(&value_to_string(value)),
+                    None => message.push_str(&tail[..len]),
+                }
+                rest = &tail[len..];
+            }
+            None => {
+                message.push('{');
+                rest = &tail[1..];
+            }
+        }
+    }
+    message.push_str(rest);
+    message
+}
+
+/// Parse `{name}` or `{name:format}` at the start of `s`, returning the name and placeholder length
+fn parse_placeholder(s: &str) -> Option<(&str, usize)> {
+    let end = s.find('}')?;
+    let inner = &s[1..end];
+    let name = inner.split(':').next().unwrap_or_default();
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '.' || c == '-');
+    match valid {
+        true => Some((name, end + 1)),
+        false => None,
+    }
+}
+
+impl LoggerOperation {
+    /// Set message template, its arguments are merged into meta and the message is rendered
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, Meta};
+    /// let logger = Logger::new("user-service");
+    /// let args = Meta::from(vec!(["pool", "abc"]));
+    /// let operation = logger.info("").with_template("Swap on {pool}", args);
+    /// assert_eq!(operation.message, "Swap on abc");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_template(self, template: &str, args: Meta) -> Self {
+        let operation = self.with(args);
+        LoggerOperation {
+            message: render_template(template, &Meta::from(&operation)),
+            message_template: Some(template.to_string()),
+            ..operation
+        }
+    }
+
+    /// Render message from the template and current meta, or the message if there is no template
+    #[must_use]
+    pub fn render(&self) -> String {
+        match &self.message_template {
+            Some(template) => render_template(template, &Meta::from(self)),
+            None => self.message.to_owned(),
+        }
+    }
+}
+
+impl Logger {
+    /// Log message template with its arguments at a level picked at runtime
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Logger, LoggingLevels, Meta};
+    /// let logger = Logger::new("user-service");
+    /// let args = Meta::from(vec!(["amount", "1.5"], ["pool", "abc"]));
+    /// let operation = logger.log_template(LoggingLevels::Info, "Swap of {amount} on {pool}", args);
+    /// assert_eq!(operation.message_template.unwrap(), "Swap of {amount} on {pool}");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn log_template<L: Into<Level>>(
+        &self,
+        level: L,
+        template: &str,
+        args: Meta,
+    ) -> LoggerOperation {
+        self.log(level, template).with_template(template, args)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LoggingLevels;
+
+    #[test]
+    fn test_render_template() {
+        let mut args = Meta::new();
+        args.insert("amount", 1.5);
+        args.insert("pool", "abc");
+        args.insert("tags", vec!["a", "b"]);
+
+        assert_eq!(
+            render_template("Swap of {amount} on {pool}", &args),
+            "Swap of 1.5 on abc"
+        );
+        assert_eq!(render_template("{tags:l}", &args), r#"["a","b"]"#);
+        assert_eq!(render_template("{{pool}} {pool}}}", &args), "{pool} abc}");
+        assert_eq!(render_template("{missing} {pool", &args), "{missing} {pool");
+        assert_eq!(render_template("{} { pool } }", &args), "{} { pool } }");
+        assert_eq!(render_template("", &args), "");
+    }
+
+    #[test]
+    fn test_log_template() {
+        let logger =
+            Logger::new("user-service").with_default_meta(Meta::from(vec![["network", "mainnet"]]));
+        let operation = logger.log_template(
+            LoggingLevels::Info,
+            "Swap on {pool} ({network})",
+            Meta::from(vec![["pool", "abc"]]),
+        );
+
+        assert_eq!(operation.message, "Swap on abc (mainnet)");
+        assert_eq!(
+            operation.message_template.as_deref(),
+            Some("Swap on {pool} ({network})")
+        );
+        assert_eq!(operation.render(), operation.message);
+    }
+}
@@ -0,0 +1,96 @@
+use crate::Meta;
+
+/// Conversion of a domain type into `Meta`
+///
+/// Derive it with `#[derive(ToMeta)]` when the `derive` feature is enabled.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{Logger, Meta, ToMeta};
+///
+/// struct Transfer { from: String, amount: u64 }
+///
+/// impl ToMeta for Transfer {
+///     fn to_meta(&self) -> Meta {
+///         Meta::from(vec![("from", self.from.as_str())])
+///     }
+/// }
+///
+/// let transfer = Transfer { from: "alice".to_string(), amount: 10 };
+/// Logger::new("token").info("transfer").with(transfer.to_meta());
+/// ```
+pub trait ToMeta {
+    fn to_meta(&self) -> Meta;
+}
+
+impl ToMeta for Meta {
+    #[inline]
+    fn to_meta(&self) -> Meta {
+        self.to_owned()
+    }
+}
+
+/// Formatting helpers called by the code generated with `#[derive(ToMeta)]`
+#[doc(hidden)]
+pub mod __private {
+    use crate::{format_amount, helpers::to_hex};
+    use std::fmt::Display;
+
+    pub fn hex<T: AsRef<[u8]> + ?Sized>(bytes: &T) -> String {
+        to_hex(bytes.as_ref())
+    }
+
+    pub fn decimals<T: Display + ?Sized>(amount: &T, decimals: u32) -> String {
+        format_amount(amount, decimals)
+    }
+}
+
+#[cfg(all(test, feature = "derive"))]
+mod tests {
+    use crate::{IntoValue, Meta, ToMeta};
+
+    #[derive(ToMeta)]
+    struct Transfer<'a> {
+        #[meta(rename = "sender")]
+        from: &'a str,
+        to: String,
+        #[meta(decimals = 4)]
+        amount: u128,
+        #[meta(hex)]
+        tx: Vec<u8>,
+        #[meta(private)]
+        memo: Option<String>,
+        #[meta(skip)]
+        #[allow(dead_code)]
+        raw: Vec<u8>,
+    }
+
+    #[test]
+    fn test_derive_to_meta() {
+        let transfer = Transfer {
+            from: "alice",
+            to: "bob".to_string(),
+            amount: 12345,
+            tx: vec![0xab, 0xcd],
+            memo: Some("secret".to_string()),
+            raw: vec![],
+        };
+        let meta = transfer.to_meta();
+
+        assert_eq!(
+            meta.keys().collect::<Vec<_>>(),
+            vec!["sender", "to", "amount", "tx", "memo"]
+        );
+        assert_eq!(meta.get("sender"), Some(&"alice".into_value()));
+        assert_eq!(meta.get("amount"), Some(&"1.2345".into_value()));
+        assert_eq!(meta.get("tx"), Some(&"0xabcd".into_value()));
+        assert!(meta.is_private("memo"));
+        assert!(!meta.is_private("to"));
+    }
+
+    #[test]
+    fn test_meta_to_meta() {
+        let meta = Meta::from(vec![("key", 1)]);
+        assert_eq!(meta.to_meta(), meta);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{helpers::encode_hex, LoggerOperation};
+use sha2::{Digest, Sha256};
+
+/// Derive an OpenTelemetry trace ID from a block hash and transaction index
+///
+/// Deterministic, replaying the same block always yields the same trace ID.
+/// The block hash is case-insensitive and may include a `0x` prefix.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::derive_trace_id;
+/// assert_eq!(derive_trace_id("0xABC", 1), derive_trace_id("abc", 1));
+/// ```
+#[must_use]
+pub fn derive_trace_id(block_hash: &str, transaction_index: u32) -> [u8; 16] {
+    let block_hash = block_hash.trim_start_matches("0x").to_lowercase();
+    let digest = Sha256::new()
+        .chain_update(b"trace")
+        .chain_update(block_hash.as_bytes())
+        .chain_update(transaction_index.to_be_bytes())
+        .finalize();
+    let mut trace_id = [0u8; 16];
+    trace_id.copy_from_slice(&digest[..16]);
+    trace_id
+}
+
+/// Derive an OpenTelemetry span ID from a trace ID and event index
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{derive_span_id, derive_trace_id};
+/// let trace_id = derive_trace_id("abc", 1);
+/// let span_id = derive_span_id(&trace_id, 0);
+/// ```
+#[must_use]
+pub fn derive_span_id(trace_id: &[u8; 16], event_index: u32) -> [u8; 8] {
+    let digest = Sha256::new()
+        .chain_update(b"span")
+        .chain_update(trace_id)
+        .chain_update(event_index.to_be_bytes())
+        .finalize();
+    let mut span_id = [0u8; 8];
+    span_id.copy_from_slice(&digest[..8]);
+    span_id
+}
+
+impl LoggerOperation {
+    /// Set OpenTelemetry trace and span IDs
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::Logger;
+    /// let logger = Logger::new("user-service");
+    /// let operation = logger.info("message").with_trace(&[1; 16], &[2; 8]);
+    /// assert_eq!(operation.span_id.unwrap(), "0202020202020202");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_trace(self, trace_id: &[u8; 16], span_id: &[u8; 8]) -> Self {
+        LoggerOperation {
+            trace_id: Some(encode_hex(trace_id)),
+            span_id: Some(encode_hex(span_id)),
+            ..self
+        }
+    }
+
+    /// Set trace and span IDs derived from the operation block hash, a transaction index
+    /// and the operation `event_index` (0 when unset)
+    ///
+    /// Operations without a block hash are left unchanged, rather than sharing one trace.
+    ///
+    /// ### Example
+    /// ```
+    /// use substreams_sink_winston::{Block, Logger};
+    /// let clock = Block { number: 1, hash: "abc".to_string(), ..Default::default() };
+    /// let logger = Logger::new("user-service").with_clock(&clock);
+    /// let operation = logger.info("message").with_event_index(2).with_trace_context(5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_trace_context(self, transaction_index: u32) -> Self {
+        let block_hash = match self.block.as_ref().map(|block| block.hash.as_str()) {
+            Some(hash) if !hash.is_empty() => hash,
+            _ => return self,
+        };
+        let trace_id = derive_trace_id(block_hash, transaction_index);
+        let span_id = derive_span_id(&trace_id, self.event_index.unwrap_or_default());
+        self.with_trace(&trace_id, &span_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Block, Logger};
+
+    #[test]
+    fn test_derive_ids() {
+        let trace_id = derive_trace_id("abc", 1);
+
+        assert_eq!(trace_id, derive_trace_id("0xABC", 1));
+        assert_ne!(trace_id, derive_trace_id("abc", 2));
+        assert_ne!(trace_id, derive_trace_id("abd", 1));
+        assert_eq!(derive_span_id(&trace_id, 0), derive_span_id(&trace_id, 0));
+        assert_ne!(derive_span_id(&trace_id, 0), derive_span_id(&trace_id, 1));
+    }
+
+    #[test]
+    fn test_with_trace_context() {
+        let clock = Block {
+            number: 1,
+            hash: "abc".to_string(),
+            ..Default::default()
+        };
+        let logger = Logger::new("user-service").with_clock(&clock);
+        let operation = logger
+            .info("message")
+            .with_event_index(3)
+            .with_trace_context(5);
+        let replay = logger
+            .info("message")
+            .with_event_index(3)
+            .with_trace_context(5);
+        let trace_id = operation.trace_id.clone().unwrap();
+
+        assert_eq!(trace_id.len(), 32);
+        assert_eq!(operation.span_id.as_ref().unwrap().len(), 16);
+        assert_eq!(trace_id, encode_hex(&derive_trace_id("abc", 5)));
+        assert_eq!(operation, replay);
+    }
+
+    #[test]
+    fn test_with_trace_context_without_block() {
+        let operation = Logger::new("user-service")
+            .info("message")
+            .with_trace_context(5);
+
+        assert_eq!(operation.trace_id, None);
+        assert_eq!(operation.span_id, None);
+    }
+}
@@ -0,0 +1,230 @@
+use crate::Meta;
+use prost_types::{value::Kind, ListValue, NullValue, Struct, Value};
+
+/// Conversion into a typed meta `Value`
+///
+/// Numbers follow `google.protobuf.Value` semantics and are stored as `f64`,
+/// the same way winston consumers receive them as JavaScript numbers.
+/// As in the proto3 JSON mapping, 64 and 128-bit integers are always stored as strings,
+/// keeping their precision and a single JSON type per key. `isize` and `usize` are numbers,
+/// being 32-bit on the `wasm32` target Substreams modules run on.
+pub trait IntoValue {
+    fn into_value(self) -> Value;
+}
+
+impl IntoValue for Value {
+    #[inline]
+    fn into_value(self) -> Value {
+        self
+    }
+}
+
+impl IntoValue for &str {
+    #[inline]
+    fn into_value(self) -> Value {
+        Value {
+            kind: Some(Kind::StringValue(self.to_string())),
+        }
+    }
+}
+
+impl IntoValue for String {
+    #[inline]
+    fn into_value(self) -> Value {
+        Value {
+            kind: Some(Kind::StringValue(self)),
+        }
+    }
+}
+
+impl IntoValue for &String {
+    #[inline]
+    fn into_value(self) -> Value {
+        self.as_str().into_value()
+    }
+}
+
+impl IntoValue for bool {
+    #[inline]
+    fn into_value(self) -> Value {
+        Value {
+            kind: Some(Kind::BoolValue(self)),
+        }
+    }
+}
+
+macro_rules! impl_into_number_value {
+    ($($t:ty),*) => {
+        $(
+            impl IntoValue for $t {
+                #[inline]
+                fn into_value(self) -> Value {
+                    Value {
+                        kind: Some(Kind::NumberValue(self as f64)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_into_number_value!(i8, i16, i32, u8, u16, u32, isize, usize, f32, f64);
+
+macro_rules! impl_into_wide_integer_value {
+    ($($t:ty),*) => {
+        $(
+            impl IntoValue for $t {
+                #[inline]
+                fn into_value(self) -> Value {
+                    self.to_string().into_value()
+                }
+            }
+        )*
+    };
+}
+
+impl_into_wide_integer_value!(i64, u64, i128, u128);
+
+impl<T: IntoValue> IntoValue for Option<T> {
+    #[inline]
+    fn into_value(self) -> Value {
+        match self {
+            Some(value) => value.into_value(),
+            None => null_value(),
+        }
+    }
+}
+
+impl<T: IntoValue> IntoValue for Vec<T> {
+    #[inline]
+    fn into_value(self) -> Value {
+        Value {
+            kind: Some(Kind::ListValue(ListValue {
+                values: self.into_iter().map(IntoValue::into_value).collect(),
+            })),
+        }
+    }
+}
+
+impl IntoValue for Meta {
+    #[inline]
+    fn into_value(self) -> Value {
+        Value {
+            kind: Some(Kind::StructValue(self.into())),
+        }
+    }
+}
+
+impl IntoValue for Struct {
+    #[inline]
+    fn into_value(self) -> Value {
+        Value {
+            kind: Some(Kind::StructValue(self)),
+        }
+    }
+}
+
+/// Null meta `Value`
+#[inline]
+#[must_use]
+pub fn null_value() -> Value {
+    Value {
+        kind: Some(Kind::NullValue(NullValue::NullValue.into())),
+    }
+}
+
+/// Render a meta `Value` as a string
+///
+/// Strings are returned as-is, every other kind is rendered as JSON.
+///
+/// ### Example
+/// ```
+/// use substreams_sink_winston::{value_to_string, IntoValue};
+/// assert_eq!(value_to_string(&"value".into_value()), "value");
+/// assert_eq!(value_to_string(&vec![1, 2].into_value()), "[1,2]");
+/// ```
+#[must_use]
+pub fn value_to_string(value: &Value) -> String {
+    match &value.kind {
+        Some(Kind::StringValue(s)) => s.to_owned(),
+        _ => value_to_json(value),
+    }
+}
+
+/// Render a meta `Value` as JSON
+#[must_use]
+pub fn value_to_json(value: &Value) -> String {
+    match &value.kind {
+        None | Some(Kind::NullValue(_)) => "null".to_string(),
+        Some(Kind::NumberValue(n)) if n.is_finite() => n.to_string(),
+        Some(Kind::NumberValue(_)) => "null".to_string(),
+        Some(Kind::StringValue(s)) => json_string(s),
+        Some(Kind::BoolValue(b)) => b.to_string(),
+        Some(Kind::ListValue(list)) => {
+            let values: Vec<String> = list.values.iter().map(value_to_json).collect();
+            format!("[{}]", values.join(","))
+        }
+        Some(Kind::StructValue(object)) => {
+            let fields: Vec<String> = object
+                .fields
+                .iter()
+                .map(|(k, v)| format!("{}:{}", json_string(k), value_to_json(v)))
+                .collect();
+            format!("{{{}}}", fields.join(","))
+        }
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_into_value() {
+        assert_eq!(1u32.into_value().kind, Some(Kind::NumberValue(1.0)));
+        assert_eq!(true.into_value().kind, Some(Kind::BoolValue(true)));
+        assert_eq!(None::<&str>.into_value(), null_value());
+    }
+
+    #[test]
+    fn test_into_value_wide_integers() {
+        assert_eq!(1u64.into_value(), "1".into_value());
+        assert_eq!((-42i64).into_value(), "-42".into_value());
+        assert_eq!(u64::MAX.into_value(), "18446744073709551615".into_value());
+        assert_eq!(
+            u128::MAX.into_value(),
+            "340282366920938463463374607431768211455".into_value()
+        );
+        assert_eq!(7usize.into_value().kind, Some(Kind::NumberValue(7.0)));
+    }
+
+    #[test]
+    fn test_value_to_json() {
+        let mut meta = Meta::new();
+        meta.insert("quote", "a\"b");
+        meta.insert("list", vec![Some(1.5), None]);
+
+        assert_eq!(
+            value_to_json(&meta.into_value()),
+            r#"{"list":[1.5,null],"quote":"a\"b"}"#
+        );
+    }
+}